@@ -11,13 +11,18 @@ use hecs::World;
 use ratatui::{backend::CrosstermBackend, Terminal};
 
 use crate::camera::Camera;
-use crate::colony::ColonyState;
-use crate::components::{Ant, ColonyMember, Position};
+use crate::colony::{ColonyState, ColonyStatus};
+use crate::components::{Ant, ColonyMember, FoodSource, Position};
 use crate::config::SimConfig;
+use crate::flow_field::FlowField;
 use crate::input::Command;
+use crate::overrides::{self, OVERRIDES_FILE};
 use crate::render::render_frame;
-use crate::spatial::SpatialGrid;
+use crate::spatial::{SpatialGrid, NO_COLONY};
+use crate::species::{SpeciesRegistry, SPECIES_FILE};
 use crate::systems;
+use crate::systems::movement::Congestion;
+use crate::systems::food::SharingLink;
 use crate::systems::pheromone::PheromoneGrid;
 use crate::systems::water::{RainEvent, WaterGrid};
 use crate::terrain::Terrain;
@@ -34,6 +39,8 @@ pub struct App {
     pheromones: PheromoneGrid,
     water: WaterGrid,
     spatial_grid: SpatialGrid,
+    food_grid: SpatialGrid,
+    flow_field: FlowField,
     rain_event: Option<RainEvent>,
     config: SimConfig,
     running: bool,
@@ -41,10 +48,23 @@ pub struct App {
     tick: u64,
     speed_multiplier: f32,
     show_pheromones: bool,
+    escaping_ants: usize,
+    congestion: Congestion,
+    show_sharing: bool,
+    sharing_links: Vec<SharingLink>,
 }
 
 impl App {
     pub fn new() -> Result<Self> {
+        // Initialize config, with species and colony overrides from the data
+        // files if there are any. Done before touching the terminal so a bad
+        // file reports cleanly.
+        let mut config = SimConfig {
+            species: SpeciesRegistry::load(SPECIES_FILE)?,
+            ..SimConfig::default()
+        };
+        overrides::load(OVERRIDES_FILE, &mut config)?;
+
         // Initialize terminal
         enable_raw_mode()?;
         let mut stdout = io::stdout();
@@ -56,9 +76,6 @@ impl App {
         let seed = fastrand::u32(..);
         let terrain = Terrain::generate(200, 100, seed);
 
-        // Initialize config
-        let config = SimConfig::default();
-
         // Initialize pheromone grid
         let pheromones = PheromoneGrid::new(terrain.width, terrain.height, config.spawn.num_colonies, &config.pheromone);
 
@@ -81,10 +98,14 @@ impl App {
         systems::water::spawn_water_sources(&mut water, &terrain, config.water.num_water_sources);
 
         // Ensure queens have Age component
-        systems::lifecycle::ensure_queen_ages(&mut world, &config);
+        systems::lifecycle::ensure_queen_ages(&mut world, &colonies, &config);
 
         // Initialize spatial grid for neighbor lookups
         let spatial_grid = SpatialGrid::new(terrain.width, terrain.height, 8);
+        let food_grid = SpatialGrid::new(terrain.width, terrain.height, 8);
+
+        // Build nest and surface distance fields for homing ants
+        let flow_field = FlowField::new(&terrain, &colonies);
 
         // Center camera on first colony's queen
         let camera = Camera::new(0, terrain.height as i32 / 5 - 5);
@@ -98,6 +119,8 @@ impl App {
             pheromones,
             water,
             spatial_grid,
+            food_grid,
+            flow_field,
             rain_event: None,
             config,
             running: true,
@@ -105,6 +128,10 @@ impl App {
             tick: 0,
             speed_multiplier: 1.0,
             show_pheromones: true,
+            escaping_ants: 0,
+            congestion: Congestion::default(),
+            show_sharing: false,
+            sharing_links: Vec::new(),
         })
     }
 
@@ -153,6 +180,9 @@ impl App {
             Some(Command::TogglePheromones) => {
                 self.show_pheromones = !self.show_pheromones;
             }
+            Some(Command::ToggleSharing) => {
+                self.show_sharing = !self.show_sharing;
+            }
             None => {}
         }
     }
@@ -171,41 +201,110 @@ impl App {
                 self.spatial_grid.insert(entity, pos.x, pos.y, member.colony_id);
             }
 
+            // Rebuild food grid so foragers can sense nearby sources
+            self.food_grid.clear();
+            for (entity, (pos, food)) in self.world.query::<(&Position, &FoodSource)>().iter() {
+                if food.amount > 0 {
+                    self.food_grid.insert(entity, pos.x, pos.y, NO_COLONY);
+                }
+            }
+
             // === Phase 1: AI & State Updates ===
 
             // Dig AI decides what ants should do
             systems::dig::dig_ai_system(&mut self.world, &self.terrain, &self.config);
 
             // Combat AI - soldiers respond to danger, workers flee
-            systems::combat::soldier_ai_system(&mut self.world, &self.pheromones, &self.config);
-            systems::combat::flee_system(&mut self.world, &self.pheromones, &self.config);
+            systems::combat::soldier_ai_system(&mut self.world, &self.pheromones, &self.colonies, &self.config);
+            systems::combat::flee_system(&mut self.world, &self.pheromones, &self.colonies, &self.config);
 
             // === Phase 2: Movement ===
-            systems::movement::movement_system(
+
+            // Patch distance fields with last tick's dig and cave-in edits, and
+            // now and then with the water that has moved since
+            self.flow_field.update(&self.terrain, &self.colonies);
+            if self.tick % self.config.water.field_cost_interval == 0 {
+                self.flow_field.update_water(&self.terrain, &self.water);
+            }
+
+            self.congestion = systems::movement::movement_system(
                 &mut self.world,
                 &self.terrain,
                 &self.pheromones,
+                &self.water,
+                &self.flow_field,
+                &self.food_grid,
                 &self.colonies,
                 &self.config,
             );
 
+            // Detect ants that stopped making progress and kick them loose
+            self.escaping_ants = systems::movement::stuck_system(&mut self.world, &self.config);
+
             // === Phase 3: Actions ===
 
             // Digging (ants in dig state remove soil)
-            systems::dig::dig_system(&mut self.world, &mut self.terrain, &self.config);
+            systems::dig::dig_system(&mut self.world, &mut self.terrain, &mut self.flow_field, &self.colonies, &self.config);
 
-            // Foraging (pickup and deposit food)
+            // Foraging (pick up food; foragers unload through trophallaxis)
             systems::food::foraging_system(
                 &mut self.world,
                 &self.terrain,
                 &self.pheromones,
-                &mut self.colonies,
+                &self.colonies,
                 &self.config,
             );
             systems::food::check_deposit(&mut self.world, &self.colonies, &self.config);
+            systems::food::tandem_system(&mut self.world, &self.config);
+            systems::food::memory_decay_system(&mut self.world, &self.config);
+
+            // Brood care (nurses move eggs and larvae to the nursery and feed them)
+            systems::lifecycle::brood_care_system(
+                &mut self.world,
+                &self.terrain,
+                &self.water,
+                &mut self.colonies,
+                &mut self.flow_field,
+                &self.spatial_grid,
+                self.tick,
+                &self.config,
+            );
+
+            // Fungus gardens (leafcutters farm fungus on the leaves they bring in)
+            systems::food::fungus_garden_system(
+                &mut self.world,
+                &self.water,
+                &mut self.colonies,
+                &self.spatial_grid,
+                self.tick,
+                &self.config,
+            );
+
+            // Repletes (surplus food is stored in living ants deep in the nest)
+            systems::food::replete_system(
+                &mut self.world,
+                &self.terrain,
+                &self.water,
+                &mut self.colonies,
+                &mut self.flow_field,
+                &self.spatial_grid,
+                self.tick,
+                &self.config,
+            );
+
+            // Trophallaxis (crop food passes mouth to mouth through the nest)
+            let links = systems::food::trophallaxis_system(
+                &mut self.world, &self.spatial_grid, &mut self.colonies, self.tick, &self.config,
+            );
+            let tick = self.tick;
+            let link_ticks = self.config.food.share_link_ticks;
+            self.sharing_links.retain(|link| tick - link.tick < link_ticks);
+            self.sharing_links.extend(links);
 
             // Combat (every 5 ticks)
-            systems::combat::combat_system(&mut self.world, &mut self.pheromones, self.tick, &self.spatial_grid, &self.config);
+            systems::combat::combat_system(
+                &mut self.world, &mut self.pheromones, self.tick, &self.spatial_grid, &self.colonies, &self.config,
+            );
 
             // Aphid farming
             systems::aphid::aphid_system(&mut self.world, &mut self.colonies, &self.config);
@@ -215,7 +314,7 @@ impl App {
             systems::pheromone::pheromone_decay_system(&mut self.pheromones, &self.config);
 
             // 2. Diffuse (spread gradients spatially to create detectable trails)
-            self.pheromones.diffuse(&self.config.pheromone);
+            self.pheromones.diffuse(&self.config);
 
             // 3. Then deposit new pheromone from ant positions (adaptive rates)
             systems::pheromone::pheromone_deposit_system(
@@ -223,7 +322,35 @@ impl App {
             );
 
             // === Phase 5: Lifecycle ===
-            systems::lifecycle::lifecycle_system(&mut self.world, &mut self.colonies, self.tick, &self.config);
+            systems::lifecycle::lifecycle_system(
+                &mut self.world, &self.terrain, &mut self.colonies, self.tick, &self.config,
+            );
+
+            // Nuptial flights (alates leave and fertilized queens found colonies)
+            if self.tick % self.config.colony.flight_interval == 0 {
+                let extinct: Vec<u8> = self
+                    .colonies
+                    .iter()
+                    .filter(|c| c.status == ColonyStatus::Extinct)
+                    .map(|c| c.id)
+                    .collect();
+                systems::lifecycle::nuptial_flight_system(
+                    &mut self.world,
+                    &mut self.terrain,
+                    &mut self.colonies,
+                    &mut self.flow_field,
+                    &self.config,
+                );
+                // Founders in reused slots don't inherit the old colony's overrides
+                for id in extinct {
+                    if self.colonies[id as usize].status != ColonyStatus::Extinct {
+                        self.config.clear_colony_overrides(id);
+                    }
+                }
+            }
+
+            // Give new colonies pheromone planes and free those of extinct ones
+            self.pheromones.sync_colonies(&self.colonies);
 
             // Food regrow
             systems::food::food_regrow_system(&mut self.world, self.tick, &self.config);
@@ -232,7 +359,7 @@ impl App {
 
             // Cave-ins (every N ticks)
             if self.tick % self.config.hazard.cave_in_interval == 0 {
-                systems::hazard::cave_in_system(&mut self.terrain, &mut self.world, &self.config);
+                systems::hazard::cave_in_system(&mut self.terrain, &mut self.world, &mut self.flow_field, &self.config);
             }
 
             // Water physics (every N ticks for performance)
@@ -254,7 +381,7 @@ impl App {
             systems::water::flee_flood_system(&mut self.world, &self.water, &self.config);
 
             // === Phase 7: Cleanup ===
-            systems::hazard::cleanup_dead(&mut self.world);
+            systems::hazard::cleanup_dead(&mut self.world, &mut self.colonies);
         }
     }
 
@@ -281,11 +408,17 @@ impl App {
         let raining = self.rain_event.is_some();
         let pheromones = &self.pheromones;
         let show_pheromones = self.show_pheromones;
+        let escaping_ants = self.escaping_ants;
+        let congestion = self.congestion;
+        let show_sharing = self.show_sharing;
+        let sharing_links = &self.sharing_links;
+        let config = &self.config;
 
         self.terminal.draw(|frame| {
             render_frame(
                 frame, terrain, water, world, colonies, camera, tick, paused, speed, raining,
-                pheromones, show_pheromones,
+                pheromones, show_pheromones, escaping_ants, congestion, show_sharing, sharing_links,
+                config,
             );
         })?;
         Ok(())
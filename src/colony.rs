@@ -12,6 +12,78 @@ pub const COLONY_COLORS: [Color; 6] = [
     Color::Green,
 ];
 
+/// Color for any colony id: the predefined palette first, then hues spread
+/// around the color wheel by the golden angle so neighbours stay distinct
+pub fn colony_color(id: u8) -> Color {
+    if let Some(&color) = COLONY_COLORS.get(id as usize) {
+        return color;
+    }
+
+    let hue = (id as f32 * 137.508) % 360.0;
+    let sector = hue / 60.0;
+    let rising = (sector % 1.0 * 255.0) as u8;
+    let falling = 255 - rising;
+    match sector as u8 {
+        0 => Color::Rgb(255, rising, 0),
+        1 => Color::Rgb(falling, 255, 0),
+        2 => Color::Rgb(0, 255, rising),
+        3 => Color::Rgb(0, falling, 255),
+        4 => Color::Rgb(rising, 0, 255),
+        _ => Color::Rgb(255, 0, falling),
+    }
+}
+
+/// Where a colony stands after losing (or keeping) its queen
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColonyStatus {
+    Thriving,
+    /// No queen, but a successor may still be raised
+    Queenless,
+    /// No queen and no brood left to raise one; the colony is dying out
+    Collapsing,
+    /// Every member is dead
+    Extinct,
+}
+
+/// Why an ant died, attached alongside `Dead` so cleanup can tally it
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeathCause {
+    Starvation,
+    OldAge,
+    Combat,
+    Drowned,
+    Crushed,
+    Neglect,
+}
+
+/// Running totals of colony deaths by cause
+#[derive(Debug, Default)]
+pub struct DeathCounts {
+    pub starved: u32,
+    pub old_age: u32,
+    pub combat: u32,
+    pub drowned: u32,
+    pub crushed: u32,
+    pub neglected: u32,
+}
+
+impl DeathCounts {
+    pub fn record(&mut self, cause: DeathCause) {
+        match cause {
+            DeathCause::Starvation => self.starved += 1,
+            DeathCause::OldAge => self.old_age += 1,
+            DeathCause::Combat => self.combat += 1,
+            DeathCause::Drowned => self.drowned += 1,
+            DeathCause::Crushed => self.crushed += 1,
+            DeathCause::Neglect => self.neglected += 1,
+        }
+    }
+
+    pub fn total(&self) -> u32 {
+        self.starved + self.old_age + self.combat + self.drowned + self.crushed + self.neglected
+    }
+}
+
 #[derive(Debug)]
 pub struct ColonyState {
     pub id: u8,
@@ -20,34 +92,62 @@ pub struct ColonyState {
     pub queen_alive: bool,
     pub home_x: i32,
     pub home_y: i32,
+    pub species: usize, // index into the species registry
+    pub nursery: Option<(i32, i32)>,
+    pub larder: Option<(i32, i32)>, // deep chamber where repletes hang
+    pub deaths: DeathCounts,
+    pub status: ColonyStatus,
+    pub queenless_since: Option<u64>,
+    pub extinct_at: Option<u64>,
+    pub males_reared: u32,
+    pub lay_rate: f32, // expected eggs per lay interval, all queens together
 }
 
 impl ColonyState {
-    pub fn new(id: u8, home_x: i32, home_y: i32, initial_food: u32) -> Self {
+    pub fn new(id: u8, species: usize, home_x: i32, home_y: i32, initial_food: u32) -> Self {
         Self {
             id,
-            color: COLONY_COLORS[id as usize % COLONY_COLORS.len()],
+            color: colony_color(id),
             food_stored: initial_food,
             queen_alive: true,
             home_x,
             home_y,
+            species,
+            nursery: None,
+            larder: None,
+            deaths: DeathCounts::default(),
+            status: ColonyStatus::Thriving,
+            queenless_since: None,
+            extinct_at: None,
+            males_reared: 0,
+            lay_rate: 0.0,
         }
     }
 
     pub fn population_summary(&self, world: &hecs::World) -> PopulationCount {
         use crate::components::{Ant, AntRole, ColonyMember};
+        use crate::config::WorkerSize;
+        use crate::systems::lifecycle::Pupa;
 
         let mut count = PopulationCount::default();
 
-        for (_entity, (ant, member)) in world.query::<(&Ant, &ColonyMember)>().iter() {
+        for (entity, (ant, member)) in world.query::<(&Ant, &ColonyMember)>().iter() {
             if member.colony_id != self.id {
                 continue;
             }
             match ant.role {
                 AntRole::Queen => count.queens += 1,
-                AntRole::Worker => count.workers += 1,
+                AntRole::Worker => {
+                    count.workers += 1;
+                    match world.get::<&WorkerSize>(entity).map(|s| *s) {
+                        Ok(WorkerSize::Minor) => count.minors += 1,
+                        Ok(WorkerSize::Major) => count.majors += 1,
+                        _ => {}
+                    }
+                }
                 AntRole::Soldier => count.soldiers += 1,
                 AntRole::Egg => count.eggs += 1,
+                AntRole::Larvae if world.get::<&Pupa>(entity).is_ok() => count.pupae += 1,
                 AntRole::Larvae => count.larvae += 1,
             }
         }
@@ -60,13 +160,16 @@ impl ColonyState {
 pub struct PopulationCount {
     pub queens: u16,
     pub workers: u16,
+    pub minors: u16, // of the workers
+    pub majors: u16, // of the workers
     pub soldiers: u16,
     pub eggs: u16,
     pub larvae: u16,
+    pub pupae: u16,
 }
 
 impl PopulationCount {
     pub fn total(&self) -> u16 {
-        self.queens + self.workers + self.soldiers + self.eggs + self.larvae
+        self.queens + self.workers + self.soldiers + self.eggs + self.larvae + self.pupae
     }
 }
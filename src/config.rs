@@ -1,3 +1,5 @@
+use crate::species::SpeciesRegistry;
+
 #[derive(Clone, Debug)]
 pub struct SimConfig {
     pub pheromone: PheromoneConfig,
@@ -9,6 +11,12 @@ pub struct SimConfig {
     pub colony: ColonyConfig,
     pub water: WaterConfig,
     pub hazard: HazardConfig,
+    pub recruitment: RecruitmentConfig,
+    pub worker_sizes: WorkerSizeConfig,
+    pub fungus: FungusConfig,
+    pub repletes: RepleteConfig,
+    pub species: SpeciesRegistry,
+    pub colony_overrides: Vec<ColonyOverrides>, // indexed by colony id
 }
 
 impl Default for SimConfig {
@@ -23,25 +31,92 @@ impl Default for SimConfig {
             colony: ColonyConfig::default(),
             water: WaterConfig::default(),
             hazard: HazardConfig::default(),
+            recruitment: RecruitmentConfig::default(),
+            worker_sizes: WorkerSizeConfig::default(),
+            fungus: FungusConfig::default(),
+            repletes: RepleteConfig::default(),
+            species: SpeciesRegistry::builtin(),
+            colony_overrides: Vec::new(),
+        }
+    }
+}
+
+impl SimConfig {
+    /// Overrides for one colony, growing the list as needed; filled from
+    /// `data/colonies.ini` at startup, or directly, e.g.
+    /// `config.override_colony(1).combat = Some(CombatConfig { soldier_strength: 60, ..Default::default() })`
+    pub fn override_colony(&mut self, colony_id: u8) -> &mut ColonyOverrides {
+        let index = colony_id as usize;
+        if self.colony_overrides.len() <= index {
+            self.colony_overrides.resize_with(index + 1, ColonyOverrides::default);
         }
+        &mut self.colony_overrides[index]
+    }
+
+    /// Forget a colony's overrides once a founder has taken over its slot
+    pub fn clear_colony_overrides(&mut self, colony_id: u8) {
+        if let Some(overrides) = self.colony_overrides.get_mut(colony_id as usize) {
+            *overrides = ColonyOverrides::default();
+        }
+    }
+
+    /// Combat settings for a colony's ants
+    pub fn combat_for(&self, colony_id: u8) -> &CombatConfig {
+        self.overrides(colony_id).and_then(|o| o.combat.as_ref()).unwrap_or(&self.combat)
+    }
+
+    /// Lifecycle settings for a colony's ants and brood
+    pub fn lifecycle_for(&self, colony_id: u8) -> &LifecycleConfig {
+        self.overrides(colony_id).and_then(|o| o.lifecycle.as_ref()).unwrap_or(&self.lifecycle)
+    }
+
+    /// Movement and digging settings for a colony's ants
+    pub fn movement_for(&self, colony_id: u8) -> &MovementConfig {
+        self.overrides(colony_id).and_then(|o| o.movement.as_ref()).unwrap_or(&self.movement)
+    }
+
+    /// Pheromone settings for a colony's trails and planes
+    pub fn pheromone_for(&self, colony_id: u8) -> &PheromoneConfig {
+        self.overrides(colony_id).and_then(|o| o.pheromone.as_ref()).unwrap_or(&self.pheromone)
+    }
+
+    fn overrides(&self, colony_id: u8) -> Option<&ColonyOverrides> {
+        self.colony_overrides.get(colony_id as usize)
     }
 }
 
+/// Config sections replaced for a single colony; `None` keeps the shared section.
+/// Tick intervals and grid-wide limits (tile capacity, pheromone strength cap
+/// and gradient threshold) apply to every colony at once, so they stay shared.
+#[derive(Clone, Debug, Default)]
+pub struct ColonyOverrides {
+    pub combat: Option<CombatConfig>,
+    pub lifecycle: Option<LifecycleConfig>,
+    pub movement: Option<MovementConfig>,
+    pub pheromone: Option<PheromoneConfig>,
+}
+
 #[derive(Clone, Debug)]
 pub struct PheromoneConfig {
     pub max_strength: f32,
     pub decay_food: f32,
     pub decay_home: f32,
     pub decay_danger: f32,
+    pub decay_recruit: f32,
     pub snap_to_zero: f32,
     pub deposit_food: f32,
     pub deposit_home: f32,
     pub deposit_danger: f32,
+    pub deposit_recruit: f32,
     pub diffusion_rate: f32,
+    pub diffusion_rate_recruit: f32,
     pub home_deposit_radius: f32,
     pub dig_deposit_radius: f32,
     pub dig_deposit_multiplier: f32,
+    pub recruit_deposit_radius: f32,
     pub gradient_threshold: f32,
+    pub recruit_response_threshold: f32,
+    pub recruit_response_gain: f32,
 }
 
 impl Default for PheromoneConfig {
@@ -51,15 +126,21 @@ impl Default for PheromoneConfig {
             decay_food: 0.02,
             decay_home: 0.005,
             decay_danger: 0.05,
+            decay_recruit: 0.12,
             snap_to_zero: 0.001,
             deposit_food: 0.05,
             deposit_home: 0.03,
             deposit_danger: 0.10,
+            deposit_recruit: 0.25,
             diffusion_rate: 0.05,
+            diffusion_rate_recruit: 0.30,
             home_deposit_radius: 30.0,
             dig_deposit_radius: 20.0,
             dig_deposit_multiplier: 0.5,
+            recruit_deposit_radius: 12.0, // recruit signal fades out this far from the food
             gradient_threshold: 0.01,
+            recruit_response_threshold: 0.05,
+            recruit_response_gain: 2.0, // join chance per tick = strength * gain
         }
     }
 }
@@ -79,7 +160,6 @@ pub struct CombatConfig {
     pub stop_fight_threshold: f32,
     pub flee_danger_threshold: f32,
     pub stop_flee_threshold: f32,
-    pub max_colonies_scan: u8,
 }
 
 impl Default for CombatConfig {
@@ -98,7 +178,6 @@ impl Default for CombatConfig {
             stop_fight_threshold: 0.05,
             flee_danger_threshold: 0.3,
             stop_flee_threshold: 0.1,
-            max_colonies_scan: 6,
         }
     }
 }
@@ -107,6 +186,7 @@ impl Default for CombatConfig {
 pub struct LifecycleConfig {
     pub egg_hatch_time: u32,
     pub larvae_mature_time: u32,
+    pub pupa_time: u32,
     pub queen_lay_interval: u32,
     pub food_per_egg: u32,
     pub worker_lifespan: u32,
@@ -115,7 +195,35 @@ pub struct LifecycleConfig {
     pub food_consume_interval: u32,
     pub larvae_food_cost: u32,
     pub ant_food_cost: u32,
-    pub worker_ratio_threshold: u8,
+    pub soldier_nutrition: u16,
+    pub minor_nutrition: u16,
+    pub major_nutrition: u16,
+    pub larva_feed_reserve: u32,
+    pub max_soldier_fraction: f32,
+    pub satiety_max: u16,
+    pub satiety_drain: u16,
+    pub larva_satiety_drain: u16,
+    pub hungry_threshold: u16,
+    pub feed_distance: i32,
+    pub nursery_min_depth: i32,
+    pub nursery_radius: i32,
+    pub nursery_spread: i32,
+    pub nursery_check_interval: u32,
+    pub nurse_radius: i32,
+    pub nurse_assign_chance: u8,
+    pub nurse_task_ticks: u32,
+    pub neglected_growth_chance: u8,
+    pub brood_neglect_limit: u32,
+    pub pupa_warm_depth: i32,
+    pub pupa_cold_growth_chance: u8,
+    pub queen_prime_start: f32,
+    pub queen_decline_start: f32,
+    pub queen_min_fertility: f32,
+    pub lay_food_reserve: u32,
+    pub lay_food_comfort: u32,
+    pub lay_max_food_boost: f32,
+    pub brood_per_worker: f32,
+    pub lay_season_amplitude: f32,
 }
 
 impl Default for LifecycleConfig {
@@ -123,15 +231,44 @@ impl Default for LifecycleConfig {
         Self {
             egg_hatch_time: 200,
             larvae_mature_time: 300,
+            pupa_time: 250,
             queen_lay_interval: 100,
             food_per_egg: 10,
             worker_lifespan: 5000,
             soldier_lifespan: 3000,
             queen_lifespan: 50000,
             food_consume_interval: 50,
-            larvae_food_cost: 2,
-            ant_food_cost: 1,
-            worker_ratio_threshold: 204, // 204/255 ~ 80% workers
+            larvae_food_cost: 16, // per meal
+            ant_food_cost: 8, // per meal
+            soldier_nutrition: 48, // food eaten as a larva to grow into a soldier
+            minor_nutrition: 16, // below this a larva emerges as a minor worker
+            major_nutrition: 32, // from here on a worker larva emerges as a major
+            larva_feed_reserve: 60, // nurses only overfeed larvae above this stock
+            max_soldier_fraction: 0.3, // well-fed larvae become workers past this share
+            satiety_max: 100,
+            satiety_drain: 5, // per food_consume_interval
+            larva_satiety_drain: 15, // growing larvae get hungry a few times before they pupate
+            hungry_threshold: 60,
+            feed_distance: 5,
+            nursery_min_depth: 4, // tiles below the nest entrance
+            nursery_radius: 25,
+            nursery_spread: 2, // brood this close to the chamber counts as housed
+            nursery_check_interval: 100,
+            nurse_radius: 8,
+            nurse_assign_chance: 40,
+            nurse_task_ticks: 300,
+            neglected_growth_chance: 85, // 85/255 ~ a third of normal development
+            brood_neglect_limit: 800,
+            pupa_warm_depth: 12, // chambers deeper than this below the entrance run cool
+            pupa_cold_growth_chance: 128, // 128/255 ~ half speed in cool or damp chambers
+            queen_prime_start: 0.05, // fraction of lifespan spent reaching full fertility
+            queen_decline_start: 0.7, // fraction of lifespan after which fertility falls
+            queen_min_fertility: 0.2,
+            lay_food_reserve: 20, // queens stop laying below this, leaving food for adults
+            lay_food_comfort: 200, // stores at which queens lay at their base rate
+            lay_max_food_boost: 3.0, // most eggs per queen per lay interval when rich
+            brood_per_worker: 3.0, // brood the workforce can look after
+            lay_season_amplitude: 0.4, // +/- around the yearly mean, one year per flight
         }
     }
 }
@@ -147,6 +284,23 @@ pub struct MovementConfig {
     pub surface_return_chance: u8,
     pub dig_distraction_chance: u8,
     pub idle_to_wander_chance_dig: u8,
+    pub flow_follow_chance: u8,
+    pub wander_straight_chance: u8,
+    pub wander_turn_chance: u8,
+    pub wander_pause_chance: u8,
+    pub stuck_ticks: u16,
+    pub dig_stuck_ticks: u16,
+    pub escape_ticks: u8,
+    pub worker_speed: f32,
+    pub soldier_speed: f32,
+    pub queen_speed: f32,
+    pub carrying_speed_multiplier: f32,
+    pub tunnel_speed_multiplier: f32,
+    pub water_speed_multiplier: f32,
+    pub min_water_speed: f32,
+    pub flee_swim_speed: f32,
+    pub tunnel_capacity: u8,
+    pub open_capacity: u8,
 }
 
 impl Default for MovementConfig {
@@ -161,6 +315,23 @@ impl Default for MovementConfig {
             surface_return_chance: 3,
             dig_distraction_chance: 30,
             idle_to_wander_chance_dig: 5,
+            flow_follow_chance: 230, // 230/255 ~ 90% of homing steps follow the distance field
+            wander_straight_chance: 170, // 170/255 ~ 67% keep heading
+            wander_turn_chance: 70,      // 70/255 ~ 27% turn 45 degrees, rest turn 90
+            wander_pause_chance: 16,
+            stuck_ticks: 40,
+            dig_stuck_ticks: 150, // digging ants legitimately sit still between digs
+            escape_ticks: 8,
+            worker_speed: 0.85,
+            soldier_speed: 1.0,
+            queen_speed: 1.0, // queens are already throttled by queen_move_threshold
+            carrying_speed_multiplier: 0.7,
+            tunnel_speed_multiplier: 0.8,
+            water_speed_multiplier: 1.0, // scales the depth penalty of wading
+            min_water_speed: 0.1, // wading never slows an ant below this share of its speed
+            flee_swim_speed: 0.3, // least water penalty for fleeing ants, even in water too deep to wade
+            tunnel_capacity: 2,
+            open_capacity: 4,
         }
     }
 }
@@ -172,22 +343,38 @@ pub struct FoodConfig {
     pub regrow_interval: u64,
     pub regrow_rate: u8,
     pub deposit_distance: i32,
-    pub food_per_deposit: u8,
     pub food_per_pickup: u8,
     pub food_pheromone_threshold: f32,
+    pub sense_radius: i32,
+    pub memory_ticks: u32,
+    pub depleted_memory_ticks: u32,
+    pub memory_follow_chance: u8,
+    pub crop_capacity: u16,
+    pub share_amount: u16,
+    pub share_interval: u64,
+    pub satiety_per_food: u16,
+    pub share_link_ticks: u64,
 }
 
 impl Default for FoodConfig {
     fn default() -> Self {
         Self {
             num_food_sources: 15,
-            initial_amount: 100,
+            initial_amount: 1000, // food units; a pickup takes food_per_pickup scaled by size
             regrow_interval: 500,
-            regrow_rate: 1,
+            regrow_rate: 10,
             deposit_distance: 3,
-            food_per_deposit: 10,
             food_per_pickup: 10,
             food_pheromone_threshold: 0.01,
+            sense_radius: 6,
+            memory_ticks: 1500,
+            depleted_memory_ticks: 400, // sites last found empty, in case they regrow
+            memory_follow_chance: 200, // 200/255 ~ 78% of wandering steps head back to the site
+            crop_capacity: 12,
+            share_amount: 2,
+            share_interval: 5,
+            satiety_per_food: 8,
+            share_link_ticks: 30, // how long feedings stay on the sharing overlay
         }
     }
 }
@@ -220,12 +407,64 @@ impl Default for SpawnConfig {
 #[derive(Clone, Debug)]
 pub struct ColonyConfig {
     pub initial_food: u32,
+    pub status_check_interval: u64,
+    pub raise_new_queens: bool,
+    pub worker_laying: bool,
+    pub worker_laying_delay: u64,
+    pub worker_lay_chance: u8,
+    pub flight_interval: u64,
+    pub mature_population: u16,
+    pub alate_food_reserve: u32,
+    pub alate_chance: u8,
+    pub male_egg_chance: u8,
+    pub founding_food: u32,
+    pub founding_depth: i32,
+    pub founding_brood: u32,
+    pub max_foundings_per_flight: usize,
+    pub max_queens: u32,
+    pub queen_relations: QueenRelations,
+    pub raise_queen_chance: u8,
+    pub adopt_queen_chance: u8,
+    pub subordinate_fertility: f32,
+    pub queen_contact_distance: i32,
+    pub queen_execution_chance: u8,
+}
+
+/// How queens sharing a nest get along
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QueenRelations {
+    /// Every queen lays at its own fertility
+    Cooperate,
+    /// The most fertile queen dominates: the others lay little and are
+    /// attacked when they come close to the dominant queen
+    Compete,
 }
 
 impl Default for ColonyConfig {
     fn default() -> Self {
         Self {
             initial_food: 100,
+            status_check_interval: 10,
+            raise_new_queens: true, // queenless colonies rear a larva into a queen
+            worker_laying: true, // long-queenless workers lay male eggs
+            worker_laying_delay: 1500,
+            worker_lay_chance: 20, // per status check
+            flight_interval: 6000, // one nuptial flight season every this many ticks
+            mature_population: 40, // colonies this big (and fed) rear alates
+            alate_food_reserve: 150,
+            alate_chance: 40, // per emerging female in a mature colony
+            male_egg_chance: 30, // per egg a mature colony's queen lays
+            founding_food: 150, // a founding queen's own reserves, enough to rear her first brood
+            founding_depth: 4, // never shallower than the nursery depth
+            founding_brood: 2, // brood a queen rears alone before her first workers emerge
+            max_foundings_per_flight: 2,
+            max_queens: 3, // 1 keeps colonies monogynous
+            queen_relations: QueenRelations::Cooperate,
+            raise_queen_chance: 5, // per status check, mature colonies below max_queens
+            adopt_queen_chance: 128, // mated alates taken back by their home colony
+            subordinate_fertility: 0.25,
+            queen_contact_distance: 2,
+            queen_execution_chance: 30, // per status check while rivals are in contact
         }
     }
 }
@@ -252,6 +491,7 @@ pub struct WaterConfig {
     pub flee_flood_depth: u8,
     pub water_flow_interval: u64,
     pub evaporation_interval: u64,
+    pub field_cost_interval: u64,
 }
 
 impl Default for WaterConfig {
@@ -277,6 +517,7 @@ impl Default for WaterConfig {
             flee_flood_depth: 2,
             water_flow_interval: 3,
             evaporation_interval: 50,
+            field_cost_interval: 20, // ticks between re-pricing flooded tiles in the distance fields
         }
     }
 }
@@ -303,3 +544,147 @@ impl Default for HazardConfig {
         }
     }
 }
+
+#[derive(Clone, Debug)]
+pub struct RecruitmentConfig {
+    pub rich_food_threshold: u16,
+    pub food_per_recruit: u16,
+    pub max_recruits: u8,
+    pub recruit_radius: i32,
+    pub contact_distance: i32,
+    pub lost_contact_distance: i32,
+    pub lost_contact_ticks: u16,
+    pub leader_patience_ticks: u16,
+}
+
+impl Default for RecruitmentConfig {
+    fn default() -> Self {
+        Self {
+            rich_food_threshold: 500,
+            food_per_recruit: 250,
+            max_recruits: 4,
+            recruit_radius: 5,
+            contact_distance: 2,
+            lost_contact_distance: 6,
+            lost_contact_ticks: 20,
+            leader_patience_ticks: 400,
+        }
+    }
+}
+
+/// Worker body size, set by how well the larva was fed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WorkerSize {
+    Minor,
+    Media,
+    Major,
+}
+
+/// Body-size traits of one worker subcaste, as multipliers on a plain worker
+#[derive(Clone, Debug)]
+pub struct SizeTraits {
+    pub carry: f32,     // food brought home per trip
+    pub dig: f32,       // how often a digger breaks soil
+    pub strength: f32,  // combat strength
+    pub food_cost: f32, // colony food per meal
+    pub lifespan: f32,
+}
+
+#[derive(Clone, Debug)]
+pub struct WorkerSizeConfig {
+    pub minor: SizeTraits,
+    pub media: SizeTraits,
+    pub major: SizeTraits,
+}
+
+impl WorkerSizeConfig {
+    /// Traits for a worker's size; workers without one (the founding
+    /// workforce) count as medias
+    pub fn traits(&self, size: Option<&WorkerSize>) -> &SizeTraits {
+        match size {
+            Some(WorkerSize::Minor) => &self.minor,
+            Some(WorkerSize::Major) => &self.major,
+            Some(WorkerSize::Media) | None => &self.media,
+        }
+    }
+}
+
+impl Default for WorkerSizeConfig {
+    fn default() -> Self {
+        Self {
+            minor: SizeTraits { carry: 0.5, dig: 0.7, strength: 0.6, food_cost: 0.5, lifespan: 0.7 },
+            media: SizeTraits { carry: 1.0, dig: 1.0, strength: 1.0, food_cost: 1.0, lifespan: 1.0 },
+            major: SizeTraits { carry: 1.5, dig: 1.5, strength: 1.8, food_cost: 2.0, lifespan: 1.2 },
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FungusConfig {
+    pub garden_interval: u64,
+    pub seed_mass: f32,
+    pub max_mass: f32,
+    pub digest_rate: f32,
+    pub leaf_to_mass: f32,
+    pub starve_loss: f32,
+    pub food_per_mass: f32,
+    pub tend_radius: i32,
+    pub disease_chance: u8,
+    pub untended_disease_chance: u8,
+    pub disease_loss: f32,
+    pub weed_chance: u8,
+}
+
+impl Default for FungusConfig {
+    fn default() -> Self {
+        Self {
+            garden_interval: 50,
+            seed_mass: 10.0, // fungus a new garden starts from
+            max_mass: 300.0,
+            digest_rate: 10.0, // leaf material broken down per interval
+            leaf_to_mass: 2.0, // fungus grown per unit of leaf material
+            starve_loss: 0.02, // fraction of fungus lost per interval without leaves
+            food_per_mass: 0.1, // food harvested per interval per unit of fungus
+            tend_radius: 4,
+            disease_chance: 2, // per interval while tended
+            untended_disease_chance: 25, // per interval with no workers around
+            disease_loss: 0.1, // fraction of fungus a diseased garden loses per interval
+            weed_chance: 30, // per tending worker per interval to clear disease
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RepleteConfig {
+    pub interval: u64,
+    pub surplus: u32,
+    pub shortage: u32,
+    pub capacity: u32,
+    pub fill_rate: u32,
+    pub dispense_rate: u32,
+    pub max_share: u32,
+    pub larder_min_depth: i32,
+    pub larder_radius: i32,
+    pub larder_spread: i32,
+    pub larder_check_interval: u64,
+    pub capture_chance: u8,
+}
+
+impl Default for RepleteConfig {
+    fn default() -> Self {
+        Self {
+            interval: 20,
+            surplus: 300, // stores above this get pumped into repletes
+            shortage: 80, // repletes feed the stores back below this
+            capacity: 60, // food one replete holds
+            fill_rate: 6, // per replete per interval
+            dispense_rate: 6, // per replete per interval
+            max_share: 15, // percent of a colony's workers that may become repletes
+            larder_min_depth: 8, // tiles below the nest entrance
+            larder_radius: 30,
+            larder_spread: 2, // repletes this close to the chamber can fill and dispense
+            larder_check_interval: 100,
+            capture_chance: 40, // per interval per raider next to a replete
+        }
+    }
+}
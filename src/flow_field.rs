@@ -0,0 +1,441 @@
+use std::collections::VecDeque;
+
+use crate::colony::ColonyState;
+use crate::systems::water::{WaterCell, WaterGrid};
+use crate::terrain::{Terrain, TerrainType};
+
+/// Distance value for tiles that cannot reach the field's target.
+const UNREACHABLE: u16 = u16::MAX;
+
+/// Entry cost of a tile ants cannot walk into.
+const CLOSED: u16 = u16::MAX;
+
+/// Extra cost of wading into water deep enough to drown in.
+const DANGER_COST: u16 = 8;
+
+const NEIGHBORS: [(i32, i32); 8] = [
+    (0, -1), (0, 1), (-1, 0), (1, 0),
+    (-1, -1), (1, -1), (-1, 1), (1, 1),
+];
+
+/// Shortest-walk distance fields over passable terrain.
+/// One field per colony counts steps to the nest entrance and two more count
+/// steps to its nursery and larder chambers, plus one shared field counting
+/// steps to the nearest surface tile. Wading through water counts as a longer
+/// walk, so the fields route around floods where they can.
+/// Terrain edits are queued with `mark_changed` and applied in `update`, and
+/// water is re-priced in `update_water`: tiles routed through a tile that got
+/// dearer or closed lose their distance, then those and any tiles that got
+/// cheaper or opened are relaxed locally.
+pub struct FlowField {
+    width: usize,
+    height: usize,
+    nest: Vec<Vec<u16>>,    // per colony
+    nursery: Vec<Vec<u16>>, // per colony, unreachable everywhere without a nursery
+    larder: Vec<Vec<u16>>,  // per colony, unreachable everywhere without a larder
+    surface: Vec<u16>,
+    water_cost: Vec<u16>, // entry cost of each tile from its water, as last priced
+    changed: Vec<(i32, i32)>,
+    rebuild_requested: bool,
+}
+
+/// What it costs to step onto each tile
+struct Costs<'a> {
+    width: usize,
+    height: usize,
+    terrain: &'a Terrain,
+    water: &'a [u16],
+}
+
+impl Costs<'_> {
+    fn entry(&self, x: i32, y: i32) -> u16 {
+        match index(self.width, self.height, x, y) {
+            Some(i) if self.terrain.is_passable(x, y) => self.water[i],
+            _ => CLOSED,
+        }
+    }
+}
+
+impl FlowField {
+    pub fn new(terrain: &Terrain, colonies: &[ColonyState]) -> Self {
+        let size = terrain.width * terrain.height;
+        let mut field = Self {
+            width: terrain.width,
+            height: terrain.height,
+            nest: vec![vec![UNREACHABLE; size]; colonies.len()],
+            nursery: vec![vec![UNREACHABLE; size]; colonies.len()],
+            larder: vec![vec![UNREACHABLE; size]; colonies.len()],
+            surface: vec![UNREACHABLE; size],
+            water_cost: vec![1; size],
+            changed: Vec::new(),
+            rebuild_requested: false,
+        };
+        field.rebuild(terrain, colonies);
+        field
+    }
+
+    /// Queue a tile whose terrain type changed this tick.
+    pub fn mark_changed(&mut self, x: i32, y: i32) {
+        self.changed.push((x, y));
+    }
+
+    /// Ask for a full rebuild on the next `update` (e.g. when the colony list
+    /// changes).
+    pub fn request_rebuild(&mut self) {
+        self.rebuild_requested = true;
+    }
+
+    /// Apply queued terrain changes to all fields.
+    pub fn update(&mut self, terrain: &Terrain, colonies: &[ColonyState]) {
+        if self.changed.is_empty() && !self.rebuild_requested {
+            return;
+        }
+        let changed = std::mem::take(&mut self.changed);
+        if self.rebuild_requested {
+            self.rebuild(terrain, colonies);
+            return;
+        }
+
+        // Closed tiles cut off whatever was routed through them; the cut-off
+        // tiles and any opened tiles then take the best route that is left
+        self.repair(terrain, &changed, &changed);
+    }
+
+    /// Re-price tiles whose water depth changed since the last call.
+    pub fn update_water(&mut self, terrain: &Terrain, water: &WaterGrid) {
+        let mut raised = Vec::new();
+        let mut lowered = Vec::new();
+        for y in 0..self.height as i32 {
+            for x in 0..self.width as i32 {
+                let i = y as usize * self.width + x as usize;
+                let cost = water_cost(water.get(x, y));
+                if cost > self.water_cost[i] {
+                    raised.push((x, y));
+                } else if cost < self.water_cost[i] {
+                    lowered.push((x, y));
+                }
+                self.water_cost[i] = cost;
+            }
+        }
+
+        if !raised.is_empty() || !lowered.is_empty() {
+            self.repair(terrain, &raised, &lowered);
+        }
+    }
+
+    /// Patch every field after some tiles got dearer and others cheaper
+    fn repair(&mut self, terrain: &Terrain, raised: &[(i32, i32)], lowered: &[(i32, i32)]) {
+        let costs = Costs { width: self.width, height: self.height, terrain, water: &self.water_cost };
+        let fields = self
+            .nest
+            .iter_mut()
+            .chain(self.nursery.iter_mut())
+            .chain(self.larder.iter_mut());
+        for field in fields.chain(std::iter::once(&mut self.surface)) {
+            let mut stale = invalidate(field, &costs, raised);
+            stale.extend_from_slice(lowered);
+            relax(field, &costs, &stale);
+        }
+    }
+
+    /// Recompute every field from scratch.
+    pub fn rebuild(&mut self, terrain: &Terrain, colonies: &[ColonyState]) {
+        let (width, height) = (self.width, self.height);
+        let costs = Costs { width, height, terrain, water: &self.water_cost };
+
+        self.nest.resize(colonies.len(), vec![UNREACHABLE; width * height]);
+        for (field, colony) in self.nest.iter_mut().zip(colonies) {
+            fill(field, &costs, &[(colony.home_x, colony.home_y)]);
+        }
+
+        self.nursery.resize(colonies.len(), vec![UNREACHABLE; width * height]);
+        for (field, colony) in self.nursery.iter_mut().zip(colonies) {
+            let sources: Vec<(i32, i32)> = colony.nursery.into_iter().collect();
+            fill(field, &costs, &sources);
+        }
+
+        self.larder.resize(colonies.len(), vec![UNREACHABLE; width * height]);
+        for (field, colony) in self.larder.iter_mut().zip(colonies) {
+            let sources: Vec<(i32, i32)> = colony.larder.into_iter().collect();
+            fill(field, &costs, &sources);
+        }
+
+        let mut surface_tiles = Vec::new();
+        for y in 0..height as i32 {
+            for x in 0..width as i32 {
+                if terrain.get(x, y) == Some(TerrainType::Surface) {
+                    surface_tiles.push((x, y));
+                }
+            }
+        }
+        fill(&mut self.surface, &costs, &surface_tiles);
+        self.changed.clear();
+        self.rebuild_requested = false;
+    }
+
+    /// Direction one step closer to the colony's nest
+    pub fn nest_step(&self, colony: u8, x: i32, y: i32) -> Option<(i32, i32)> {
+        let field = self.nest.get(colony as usize)?;
+        step(field, self.width, self.height, x, y)
+    }
+
+    /// Point a colony's nursery field at a new chamber (or clear it)
+    pub fn set_nursery(&mut self, colony: u8, site: Option<(i32, i32)>, terrain: &Terrain) {
+        let costs = Costs { width: self.width, height: self.height, terrain, water: &self.water_cost };
+        if let Some(field) = self.nursery.get_mut(colony as usize) {
+            let sources: Vec<(i32, i32)> = site.into_iter().collect();
+            fill(field, &costs, &sources);
+        }
+    }
+
+    /// Point a colony's larder field at a new chamber (or clear it)
+    pub fn set_larder(&mut self, colony: u8, site: Option<(i32, i32)>, terrain: &Terrain) {
+        let costs = Costs { width: self.width, height: self.height, terrain, water: &self.water_cost };
+        if let Some(field) = self.larder.get_mut(colony as usize) {
+            let sources: Vec<(i32, i32)> = site.into_iter().collect();
+            fill(field, &costs, &sources);
+        }
+    }
+
+    /// Walking distance from a tile to the colony's nest, if it can get there at all
+    pub fn nest_distance(&self, colony: u8, x: i32, y: i32) -> Option<u16> {
+        let field = self.nest.get(colony as usize)?;
+        let d = field[index(self.width, self.height, x, y)?];
+        (d != UNREACHABLE).then_some(d)
+    }
+
+    /// Direction one step closer to the colony's nursery
+    pub fn nursery_step(&self, colony: u8, x: i32, y: i32) -> Option<(i32, i32)> {
+        let field = self.nursery.get(colony as usize)?;
+        step(field, self.width, self.height, x, y)
+    }
+
+    /// Direction one step closer to the colony's larder
+    pub fn larder_step(&self, colony: u8, x: i32, y: i32) -> Option<(i32, i32)> {
+        let field = self.larder.get(colony as usize)?;
+        step(field, self.width, self.height, x, y)
+    }
+
+    /// Direction one step closer to the nearest surface tile
+    pub fn surface_step(&self, x: i32, y: i32) -> Option<(i32, i32)> {
+        step(&self.surface, self.width, self.height, x, y)
+    }
+}
+
+fn index(width: usize, height: usize, x: i32, y: i32) -> Option<usize> {
+    if x < 0 || y < 0 || x as usize >= width || y as usize >= height {
+        return None;
+    }
+    Some(y as usize * width + x as usize)
+}
+
+/// Entry cost of a tile from its water: slow wading counts as a longer walk,
+/// water deep enough to drown in as a long detour, and water too deep to wade
+/// closes the tile
+fn water_cost(cell: WaterCell) -> u16 {
+    if !cell.is_passable() {
+        return CLOSED;
+    }
+    let mut cost = (1.0 / cell.movement_penalty()).round() as u16;
+    if cell.is_dangerous() {
+        cost += DANGER_COST;
+    }
+    cost
+}
+
+/// Fill a field with walking distances from the given source tiles
+fn fill(field: &mut [u16], costs: &Costs, sources: &[(i32, i32)]) {
+    for d in field.iter_mut() {
+        *d = UNREACHABLE;
+    }
+
+    let mut queue = VecDeque::new();
+    for &(x, y) in sources {
+        if let Some(i) = index(costs.width, costs.height, x, y) {
+            if costs.entry(x, y) != CLOSED {
+                field[i] = 0;
+                queue.push_back((x, y));
+            }
+        }
+    }
+
+    propagate(field, costs, queue);
+}
+
+/// Clear the distances of tiles that got dearer or closed and of every tile
+/// whose shortest route ran through one, returning the cleared tiles
+fn invalidate(field: &mut [u16], costs: &Costs, raised: &[(i32, i32)]) -> Vec<(i32, i32)> {
+    let (width, height) = (costs.width, costs.height);
+    let mut queue: VecDeque<(i32, i32)> = raised.iter().copied().collect();
+    let mut stale = Vec::new();
+
+    while let Some((x, y)) = queue.pop_front() {
+        let Some(i) = index(width, height, x, y) else { continue };
+        let cost = costs.entry(x, y);
+        let d = field[i];
+
+        // Cleared already, or an open source tile that needs no route
+        if d == UNREACHABLE || (d == 0 && cost != CLOSED) {
+            continue;
+        }
+
+        // Still reachable at this distance through some closer neighbor
+        let supported = cost != CLOSED
+            && NEIGHBORS.iter().any(|(dx, dy)| {
+                index(width, height, x + dx, y + dy)
+                    .is_some_and(|ni| field[ni] != UNREACHABLE && field[ni].saturating_add(cost) == d)
+            });
+        if supported {
+            continue;
+        }
+
+        // Anything routed through this tile has to be checked in turn
+        field[i] = UNREACHABLE;
+        stale.push((x, y));
+        for (dx, dy) in NEIGHBORS {
+            let (nx, ny) = (x + dx, y + dy);
+            if index(width, height, nx, ny).is_some_and(|ni| field[ni] != UNREACHABLE) {
+                queue.push_back((nx, ny));
+            }
+        }
+    }
+    stale
+}
+
+/// Lower distances around tiles that got cheaper, opened or were cut off,
+/// without touching the rest of the field
+fn relax(field: &mut [u16], costs: &Costs, tiles: &[(i32, i32)]) {
+    let (width, height) = (costs.width, costs.height);
+    let mut queue = VecDeque::new();
+
+    for &(x, y) in tiles {
+        let Some(i) = index(width, height, x, y) else { continue };
+        let cost = costs.entry(x, y);
+        if cost == CLOSED {
+            continue;
+        }
+
+        let best = NEIGHBORS
+            .iter()
+            .filter_map(|(dx, dy)| index(width, height, x + dx, y + dy))
+            .map(|ni| field[ni])
+            .min()
+            .unwrap_or(UNREACHABLE);
+
+        if best != UNREACHABLE && best.saturating_add(cost) < field[i] {
+            field[i] = best.saturating_add(cost);
+            queue.push_back((x, y));
+        }
+    }
+
+    propagate(field, costs, queue);
+}
+
+/// Push improved distances outward until no neighbor can be shortened
+fn propagate(field: &mut [u16], costs: &Costs, mut queue: VecDeque<(i32, i32)>) {
+    let (width, height) = (costs.width, costs.height);
+    while let Some((x, y)) = queue.pop_front() {
+        let Some(i) = index(width, height, x, y) else { continue };
+        let here = field[i];
+
+        for (dx, dy) in NEIGHBORS {
+            let (nx, ny) = (x + dx, y + dy);
+            let cost = costs.entry(nx, ny);
+            if cost == CLOSED {
+                continue;
+            }
+            if let Some(ni) = index(width, height, nx, ny) {
+                let next = here.saturating_add(cost);
+                if next < field[ni] {
+                    field[ni] = next;
+                    queue.push_back((nx, ny));
+                }
+            }
+        }
+    }
+}
+
+/// Pick the neighbor with the lowest distance, breaking ties randomly
+fn step(field: &[u16], width: usize, height: usize, x: i32, y: i32) -> Option<(i32, i32)> {
+    let current = field[index(width, height, x, y)?];
+    if current == UNREACHABLE || current == 0 {
+        return None;
+    }
+
+    let mut best: Vec<(i32, i32)> = Vec::new();
+    let mut best_dist = current;
+
+    for (dx, dy) in NEIGHBORS {
+        let Some(ni) = index(width, height, x + dx, y + dy) else { continue };
+        let d = field[ni];
+        if d < best_dist {
+            best_dist = d;
+            best.clear();
+            best.push((dx, dy));
+        } else if d == best_dist && d < current {
+            best.push((dx, dy));
+        }
+    }
+
+    if best.is_empty() {
+        None
+    } else {
+        Some(best[fastrand::usize(..best.len())])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Passable tile on top of the ground in column `x`
+    fn ground_level(terrain: &Terrain, x: i32) -> i32 {
+        (0..terrain.height as i32)
+            .find(|&y| !terrain.is_passable(x, y + 1))
+            .expect("column has ground")
+    }
+
+    fn set_tile(terrain: &mut Terrain, field: &mut FlowField, x: i32, y: i32, tile: TerrainType) {
+        terrain.set(x, y, tile);
+        field.mark_changed(x, y);
+    }
+
+    fn assert_matches_rebuild(field: &FlowField, terrain: &Terrain, colonies: &[ColonyState]) {
+        let fresh = FlowField::new(terrain, colonies);
+        assert!(field.nest == fresh.nest, "nest field differs from a full rebuild");
+        assert!(field.surface == fresh.surface, "surface field differs from a full rebuild");
+    }
+
+    #[test]
+    fn local_repair_matches_full_rebuild() {
+        let mut terrain = Terrain::generate(80, 60, 7);
+        let (x, far) = (30, 36);
+        let top = ground_level(&terrain, x);
+        let far_top = ground_level(&terrain, far);
+        let depth = top.max(far_top) + 6;
+        let colonies = vec![ColonyState::new(0, 0, x, top, 0)];
+        let mut field = FlowField::new(&terrain, &colonies);
+
+        // Dig a shaft below the nest with a side gallery at the bottom
+        for y in top + 1..=depth {
+            set_tile(&mut terrain, &mut field, x, y, TerrainType::Tunnel);
+        }
+        for gx in x + 1..=far {
+            set_tile(&mut terrain, &mut field, gx, depth, TerrainType::Tunnel);
+        }
+        field.update(&terrain, &colonies);
+        assert_matches_rebuild(&field, &terrain, &colonies);
+
+        // Cave the shaft in halfway down, cutting the gallery off
+        set_tile(&mut terrain, &mut field, x, (top + depth) / 2, TerrainType::Soil);
+        field.update(&terrain, &colonies);
+        assert_matches_rebuild(&field, &terrain, &colonies);
+
+        // Dig a second way down to the gallery
+        for y in far_top + 1..depth {
+            set_tile(&mut terrain, &mut field, far, y, TerrainType::Tunnel);
+        }
+        field.update(&terrain, &colonies);
+        assert_matches_rebuild(&field, &terrain, &colonies);
+    }
+}
@@ -11,6 +11,7 @@ pub enum Command {
     ScrollLeft,
     ScrollRight,
     TogglePheromones,
+    ToggleSharing,
 }
 
 impl Command {
@@ -25,6 +26,7 @@ impl Command {
             KeyCode::Left | KeyCode::Char('a') | KeyCode::Char('h') => Some(Command::ScrollLeft),
             KeyCode::Right | KeyCode::Char('d') | KeyCode::Char('l') => Some(Command::ScrollRight),
             KeyCode::Char('p') | KeyCode::Char('P') => Some(Command::TogglePheromones),
+            KeyCode::Char('f') | KeyCode::Char('F') => Some(Command::ToggleSharing),
             _ => None,
         }
     }
@@ -3,9 +3,12 @@ mod camera;
 mod colony;
 mod components;
 mod config;
+mod flow_field;
 mod input;
+mod overrides;
 mod render;
 mod spatial;
+mod species;
 mod systems;
 mod terrain;
 
@@ -0,0 +1,246 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+
+use crate::config::{CombatConfig, LifecycleConfig, MovementConfig, PheromoneConfig, SimConfig};
+
+/// Per-colony settings, read from the working directory at startup when present
+pub const OVERRIDES_FILE: &str = "data/colonies.ini";
+
+/// Assign `value` to whichever listed field is named `key`
+macro_rules! set_field {
+    ($section:expr, $key:expr, $value:expr, [$($field:ident),* $(,)?]) => {{
+        $(
+            if $key == stringify!($field) {
+                $section.$field = $value.parse()?;
+                return Ok(());
+            }
+        )*
+        bail!("unknown or shared setting `{}`", $key)
+    }};
+}
+
+/// Read colony overrides from a data file into `config`; a missing file
+/// leaves every colony on the shared settings
+pub fn load(path: impl AsRef<Path>, config: &mut SimConfig) -> Result<()> {
+    let path = path.as_ref();
+    if !path.exists() {
+        return Ok(());
+    }
+    let text = fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    parse(&text, config).with_context(|| format!("parsing {}", path.display()))
+}
+
+/// Parse `[colony N]` sections of `section.setting = value` lines; `#` starts
+/// a comment. A section copies the shared settings the first time one of its
+/// values is overridden, so unlisted values keep their shared defaults.
+pub fn parse(text: &str, config: &mut SimConfig) -> Result<()> {
+    let mut colony: Option<u8> = None;
+
+    for (number, line) in text.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(header) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            let Some(id) = header.trim().strip_prefix("colony") else {
+                bail!("line {}: expected `[colony N]`", number + 1);
+            };
+            let id = id.trim().parse().with_context(|| format!("line {}: bad colony id", number + 1))?;
+            colony = Some(id);
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            bail!("line {}: expected `section.setting = value`", number + 1);
+        };
+        let Some(id) = colony else {
+            bail!("line {}: setting outside a [colony N] section", number + 1);
+        };
+        set(config, id, key.trim(), value.trim()).with_context(|| format!("line {}", number + 1))?;
+    }
+    Ok(())
+}
+
+fn set(config: &mut SimConfig, colony_id: u8, key: &str, value: &str) -> Result<()> {
+    let Some((section, setting)) = key.split_once('.') else {
+        bail!("expected `section.setting`, found `{key}`");
+    };
+
+    match section {
+        "combat" => {
+            let shared = config.combat.clone();
+            set_combat(config.override_colony(colony_id).combat.get_or_insert(shared), setting, value)
+        }
+        "lifecycle" => {
+            let shared = config.lifecycle.clone();
+            set_lifecycle(config.override_colony(colony_id).lifecycle.get_or_insert(shared), setting, value)
+        }
+        "movement" => {
+            let shared = config.movement.clone();
+            set_movement(config.override_colony(colony_id).movement.get_or_insert(shared), setting, value)
+        }
+        "pheromone" => {
+            let shared = config.pheromone.clone();
+            set_pheromone(config.override_colony(colony_id).pheromone.get_or_insert(shared), setting, value)
+        }
+        _ => bail!("unknown section `{section}`"),
+    }
+}
+
+// Tick intervals, tile capacities and the pheromone cap and gradient
+// threshold are read from the shared config only, so they are not listed.
+
+fn set_combat(combat: &mut CombatConfig, key: &str, value: &str) -> Result<()> {
+    set_field!(combat, key, value, [
+        base_damage,
+        soldier_strength,
+        worker_strength,
+        other_strength,
+        danger_deposit_amount,
+        damage_random_range,
+        default_health,
+        default_fighter_strength,
+        fight_danger_threshold,
+        stop_fight_threshold,
+        flee_danger_threshold,
+        stop_flee_threshold,
+    ])
+}
+
+fn set_lifecycle(lifecycle: &mut LifecycleConfig, key: &str, value: &str) -> Result<()> {
+    set_field!(lifecycle, key, value, [
+        egg_hatch_time,
+        larvae_mature_time,
+        pupa_time,
+        food_per_egg,
+        worker_lifespan,
+        soldier_lifespan,
+        queen_lifespan,
+        larvae_food_cost,
+        ant_food_cost,
+        soldier_nutrition,
+        minor_nutrition,
+        major_nutrition,
+        larva_feed_reserve,
+        max_soldier_fraction,
+        satiety_max,
+        satiety_drain,
+        larva_satiety_drain,
+        hungry_threshold,
+        feed_distance,
+        nursery_min_depth,
+        nursery_radius,
+        nursery_spread,
+        nurse_radius,
+        nurse_assign_chance,
+        nurse_task_ticks,
+        neglected_growth_chance,
+        brood_neglect_limit,
+        pupa_warm_depth,
+        pupa_cold_growth_chance,
+        queen_prime_start,
+        queen_decline_start,
+        queen_min_fertility,
+        lay_food_reserve,
+        lay_food_comfort,
+        lay_max_food_boost,
+        brood_per_worker,
+        lay_season_amplitude,
+    ])
+}
+
+fn set_movement(movement: &mut MovementConfig, key: &str, value: &str) -> Result<()> {
+    set_field!(movement, key, value, [
+        queen_move_threshold,
+        idle_move_threshold,
+        dig_chance,
+        reinforce_chance,
+        start_dig_chance,
+        underground_return_chance,
+        surface_return_chance,
+        dig_distraction_chance,
+        idle_to_wander_chance_dig,
+        flow_follow_chance,
+        wander_straight_chance,
+        wander_turn_chance,
+        wander_pause_chance,
+        stuck_ticks,
+        dig_stuck_ticks,
+        escape_ticks,
+        worker_speed,
+        soldier_speed,
+        queen_speed,
+        carrying_speed_multiplier,
+        tunnel_speed_multiplier,
+        water_speed_multiplier,
+        min_water_speed,
+        flee_swim_speed,
+    ])
+}
+
+fn set_pheromone(pheromone: &mut PheromoneConfig, key: &str, value: &str) -> Result<()> {
+    set_field!(pheromone, key, value, [
+        decay_food,
+        decay_home,
+        decay_danger,
+        decay_recruit,
+        snap_to_zero,
+        deposit_food,
+        deposit_home,
+        deposit_danger,
+        deposit_recruit,
+        diffusion_rate,
+        diffusion_rate_recruit,
+        home_deposit_radius,
+        dig_deposit_radius,
+        dig_deposit_multiplier,
+        recruit_deposit_radius,
+        recruit_response_threshold,
+        recruit_response_gain,
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overrides_replace_only_listed_settings() {
+        let mut config = SimConfig::default();
+        parse("[colony 1]\ncombat.soldier_strength = 60\n", &mut config).unwrap();
+        assert_eq!(config.combat_for(1).soldier_strength, 60);
+        assert_eq!(config.combat_for(1).worker_strength, config.combat.worker_strength);
+        assert_eq!(config.combat_for(0).soldier_strength, config.combat.soldier_strength);
+    }
+
+    #[test]
+    fn comments_only_is_no_overrides() {
+        let mut config = SimConfig::default();
+        parse("# nothing here\n\n", &mut config).unwrap();
+        assert!(config.colony_overrides.is_empty());
+    }
+
+    #[test]
+    fn shared_settings_are_rejected() {
+        let mut config = SimConfig::default();
+        assert!(parse("[colony 0]\ncombat.combat_interval = 2\n", &mut config).is_err());
+        assert!(parse("[colony 0]\nfood.initial_amount = 2\n", &mut config).is_err());
+    }
+
+    #[test]
+    fn setting_before_section_is_an_error() {
+        let mut config = SimConfig::default();
+        let err = parse("movement.worker_speed = 2.0\n", &mut config).unwrap_err();
+        assert!(err.to_string().contains("outside"));
+    }
+
+    #[test]
+    fn bad_header_is_an_error() {
+        let mut config = SimConfig::default();
+        assert!(parse("[fire]\n", &mut config).is_err());
+        assert!(parse("[colony 300]\n", &mut config).is_err());
+    }
+}
@@ -1,15 +1,20 @@
 use hecs::World;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
 
 use crate::camera::Camera;
-use crate::colony::{ColonyState, COLONY_COLORS};
+use crate::colony::{colony_color, ColonyState, ColonyStatus};
 use crate::components::{Ant, AntRole, AntState, Aphid, Carrying, ColonyMember, FoodSource, Position};
+use crate::config::{SimConfig, WorkerSize};
+use crate::species::SpeciesRegistry;
+use crate::systems::food::{FungusGarden, Replete, SharingLink};
+use crate::systems::lifecycle::{Alate, Nurse, NurseTask, Pupa, QueenStats, Satiety};
+use crate::systems::movement::Congestion;
 use crate::systems::pheromone::{PheromoneGrid, PheromoneType};
 use crate::systems::water::WaterGrid;
 use crate::terrain::{Terrain, TerrainType};
@@ -27,13 +32,21 @@ pub fn render_frame(
     raining: bool,
     pheromones: &PheromoneGrid,
     show_pheromones: bool,
+    escaping_ants: usize,
+    congestion: Congestion,
+    show_sharing: bool,
+    sharing_links: &[SharingLink],
+    config: &SimConfig,
 ) {
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Min(0), Constraint::Length(36)])
         .split(frame.area());
 
-    render_terrain(frame, chunks[0], terrain, water, world, camera, pheromones, show_pheromones);
+    render_terrain(
+        frame, chunks[0], terrain, water, world, camera, pheromones, show_pheromones,
+        show_sharing, sharing_links, config,
+    );
     render_stats(
         frame,
         chunks[1],
@@ -45,6 +58,9 @@ pub fn render_frame(
         paused,
         speed,
         raining,
+        escaping_ants,
+        congestion,
+        &config.species,
     );
 }
 
@@ -57,6 +73,9 @@ fn render_terrain(
     camera: &Camera,
     pheromones: &PheromoneGrid,
     show_pheromones: bool,
+    show_sharing: bool,
+    sharing_links: &[SharingLink],
+    config: &SimConfig,
 ) {
     let block = Block::default().borders(Borders::ALL).title(" World ");
 
@@ -81,17 +100,56 @@ fn render_terrain(
     for (_entity, (pos, aphid)) in world.query::<(&Position, &Aphid)>().iter() {
         let color = aphid
             .colony_owner
-            .map(|c| COLONY_COLORS[c as usize % COLONY_COLORS.len()])
+            .map(colony_color)
             .unwrap_or(Color::Green);
         entity_chars.insert((pos.x, pos.y), ('a', color));
     }
 
-    // Ants (rendered last to be on top)
-    for (_entity, (pos, ant, member)) in world.query::<(&Position, &Ant, &ColonyMember)>().iter() {
-        let (ch, color) = ant_visual(ant, member.colony_id, world.get::<&Carrying>(_entity).is_ok());
-        entity_chars.insert((pos.x, pos.y), (ch, color));
+    // Fungus gardens
+    for (_entity, (pos, garden)) in world.query::<(&Position, &FungusGarden)>().iter() {
+        let color = if garden.diseased { Color::Magenta } else { colony_color(garden.colony_id) };
+        entity_chars.insert((pos.x, pos.y), ('♣', color));
+    }
+
+    // Ants (on top of food, aphids and gardens). When several share a tile, show the
+    // most prominent one and embolden the tile so crowding stands out.
+    let mut ant_tiles: std::collections::HashMap<(i32, i32), (u8, usize)> =
+        std::collections::HashMap::new(); // (priority, count)
+    for (entity, (pos, ant, member)) in world.query::<(&Position, &Ant, &ColonyMember)>().iter() {
+        // Nurses hauling brood to the nursery show as loaded too
+        let carrying = world.get::<&Carrying>(entity).is_ok()
+            || world.get::<&Nurse>(entity).is_ok_and(|n| matches!(n.task, NurseTask::Deliver(_)));
+        let pupa = world.get::<&Pupa>(entity).is_ok();
+        let size = world.get::<&WorkerSize>(entity).ok().map(|s| *s);
+        let replete = world.get::<&Replete>(entity).is_ok();
+        let priority = ant_priority(ant, carrying);
+        let tile = ant_tiles.entry((pos.x, pos.y)).or_insert((0, 0));
+        tile.1 += 1;
+        if tile.1 == 1 || priority >= tile.0 {
+            tile.0 = priority;
+            let (ch, mut color) = ant_visual(ant, member.colony_id, carrying, pupa, replete, size);
+            if show_sharing {
+                if let Ok(satiety) = world.get::<&Satiety>(entity) {
+                    color = fullness_color(satiety.value, config.lifecycle_for(member.colony_id).satiety_max);
+                }
+            }
+            entity_chars.insert((pos.x, pos.y), (ch, color));
+        }
     }
 
+    // Alates waiting for the nuptial flight, drawn over the ants crowding the nest
+    for (_entity, (pos, alate)) in world.query::<(&Position, &Alate)>().iter() {
+        let color = colony_color(alate.colony_id);
+        entity_chars.insert((pos.x, pos.y), ('^', color));
+    }
+
+    // Tiles where ants recently fed each other
+    let shared_tiles: std::collections::HashSet<(i32, i32)> = if show_sharing {
+        sharing_links.iter().flat_map(|link| [link.from, link.to]).collect()
+    } else {
+        std::collections::HashSet::new()
+    };
+
     // Render terrain, water, and entities
     for dy in 0..view_height {
         for dx in 0..view_width {
@@ -103,9 +161,21 @@ fn render_terrain(
                 let x = inner.x + dx as u16;
                 let y = inner.y + dy as u16;
                 if x < inner.x + inner.width && y < inner.y + inner.height {
+                    let crowded = ant_tiles
+                        .get(&(world_x, world_y))
+                        .map(|(_, count)| *count > 1)
+                        .unwrap_or(false);
+                    let mut style = if crowded {
+                        Style::default().fg(*color).add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(*color)
+                    };
+                    if shared_tiles.contains(&(world_x, world_y)) {
+                        style = style.bg(Color::Rgb(90, 0, 90));
+                    }
                     frame
                         .buffer_mut()
-                        .set_string(x, y, ch.to_string(), Style::default().fg(*color));
+                        .set_string(x, y, ch.to_string(), style);
                 }
                 continue;
             }
@@ -145,22 +215,27 @@ fn render_terrain(
                     let mut bg_g: u8 = 0;
                     let mut bg_b: u8 = 0;
 
-                    for c in 0..pheromones.max_colonies as u8 {
-                        let food_val = pheromones.get(world_x, world_y, c, PheromoneType::Food);
-                        let home_val = pheromones.get(world_x, world_y, c, PheromoneType::Home);
-                        let danger_val = pheromones.get(world_x, world_y, c, PheromoneType::Danger);
-
-                        // Map intensity (0.0-1.0) to color (0-120)
-                        // Cap at 120 to preserve foreground character visibility
-                        if food_val > 0.05 {
-                            bg_g = bg_g.max((food_val.clamp(0.0, 1.0) * 120.0) as u8);
-                        }
-                        if home_val > 0.05 {
-                            bg_b = bg_b.max((home_val.clamp(0.0, 1.0) * 120.0) as u8);
-                        }
-                        if danger_val > 0.05 {
-                            bg_r = bg_r.max((danger_val.clamp(0.0, 1.0) * 120.0) as u8);
-                        }
+                    let food_val = pheromones.max_any(world_x, world_y, PheromoneType::Food);
+                    let home_val = pheromones.max_any(world_x, world_y, PheromoneType::Home);
+                    let danger_val = pheromones.max_any(world_x, world_y, PheromoneType::Danger);
+                    let recruit_val = pheromones.max_any(world_x, world_y, PheromoneType::Recruit);
+
+                    // Map intensity (0.0-1.0) to color (0-120)
+                    // Cap at 120 to preserve foreground character visibility
+                    if food_val > 0.05 {
+                        bg_g = bg_g.max((food_val.clamp(0.0, 1.0) * 120.0) as u8);
+                    }
+                    if home_val > 0.05 {
+                        bg_b = bg_b.max((home_val.clamp(0.0, 1.0) * 120.0) as u8);
+                    }
+                    if danger_val > 0.05 {
+                        bg_r = bg_r.max((danger_val.clamp(0.0, 1.0) * 120.0) as u8);
+                    }
+                    // Recruit shows as yellow (red + green)
+                    if recruit_val > 0.05 {
+                        let level = (recruit_val.clamp(0.0, 1.0) * 120.0) as u8;
+                        bg_r = bg_r.max(level);
+                        bg_g = bg_g.max(level);
                     }
 
                     if bg_r > 0 || bg_g > 0 || bg_b > 0 {
@@ -180,20 +255,44 @@ fn render_terrain(
     }
 }
 
+/// Which ant to draw when several share a tile (higher wins)
+fn ant_priority(ant: &Ant, carrying: bool) -> u8 {
+    match ant.role {
+        AntRole::Queen => 4,
+        AntRole::Soldier if ant.state == AntState::Fighting => 3,
+        _ if carrying => 2,
+        AntRole::Worker | AntRole::Soldier => 1,
+        _ => 0,
+    }
+}
+
 /// Get visual representation of an ant
-fn ant_visual(ant: &Ant, colony_id: u8, carrying: bool) -> (char, Color) {
-    let color = COLONY_COLORS[colony_id as usize % COLONY_COLORS.len()];
+fn ant_visual(
+    ant: &Ant,
+    colony_id: u8,
+    carrying: bool,
+    pupa: bool,
+    replete: bool,
+    size: Option<WorkerSize>,
+) -> (char, Color) {
+    let color = colony_color(colony_id);
 
     let ch = match ant.role {
         AntRole::Queen => 'Q',
         AntRole::Worker => {
-            if carrying {
-                '●' // Carrying food
+            if replete {
+                '◎' // Storing food in the larder
+            } else if carrying {
+                '●' // Carrying food or brood
             } else {
                 match ant.state {
                     AntState::Digging => '⚒',
                     AntState::Fleeing => '!',
-                    _ => '•',
+                    _ => match size {
+                        Some(WorkerSize::Minor) => '·',
+                        Some(WorkerSize::Major) => '◆',
+                        _ => '•',
+                    },
                 }
             }
         }
@@ -205,12 +304,24 @@ fn ant_visual(ant: &Ant, colony_id: u8, carrying: bool) -> (char, Color) {
             }
         }
         AntRole::Egg => '°',
-        AntRole::Larvae => 'o',
+        AntRole::Larvae => {
+            if pupa {
+                '0' // Spun into a cocoon
+            } else {
+                'o'
+            }
+        }
     };
 
     (ch, color)
 }
 
+/// Red (empty) to green (full) for the sharing overlay
+fn fullness_color(satiety: u16, max: u16) -> Color {
+    let fullness = (satiety as f32 / max.max(1) as f32).clamp(0.0, 1.0);
+    Color::Rgb(((1.0 - fullness) * 255.0) as u8, (fullness * 255.0) as u8, 0)
+}
+
 /// Get visual representation of water
 fn water_visual(depth: u8) -> (char, Color) {
     let ch = match depth {
@@ -240,6 +351,9 @@ fn render_stats(
     paused: bool,
     speed: f32,
     raining: bool,
+    escaping_ants: usize,
+    congestion: Congestion,
+    species: &SpeciesRegistry,
 ) {
     let block = Block::default().borders(Borders::ALL).title(" AntTrails ");
 
@@ -273,53 +387,146 @@ fn render_stats(
             ),
             Span::raw(format!(" {}", status)),
         ]),
+        Line::from(vec![
+            Span::raw("Escaping: "),
+            Span::styled(format!("{}", escaping_ants), Style::default().fg(Color::Red)),
+            Span::raw(" Jams: "),
+            Span::styled(
+                format!("{}/{}", congestion.blocked_moves, congestion.full_tiles),
+                Style::default().fg(Color::Yellow),
+            ),
+        ]),
         Line::raw(""),
         Line::styled("─ Controls ─", Style::default().fg(Color::Cyan)),
         Line::raw("[Space] Pause/Resume"),
         Line::raw("[+/-]   Speed up/down"),
         Line::raw("[Arrows] Scroll"),
         Line::raw("[P]     Pheromones"),
+        Line::raw("[F]     Food sharing"),
         Line::raw("[Q]     Quit"),
         Line::raw(""),
         Line::styled("─ Legend ─", Style::default().fg(Color::Cyan)),
         Line::raw("Q=Queen •=Worker *=Soldier"),
-        Line::raw("°=Egg o=Larvae a=Aphid"),
-        Line::raw("♠=Food ~=Water"),
+        Line::raw("·=Minor ◆=Major worker ◎=Replete"),
+        Line::raw("°=Egg o=Larvae 0=Pupa a=Aphid"),
+        Line::raw("♠=Food ~=Water ^=Alate"),
+        Line::raw("♣=Fungus garden"),
         Line::raw(""),
         Line::styled("─ Colonies ─", Style::default().fg(Color::Cyan)),
     ];
 
     // Add colony info
     for colony in colonies {
+        // Extinct colonies collapse to a single greyed-out line
+        if colony.status == ColonyStatus::Extinct {
+            lines.push(Line::styled(
+                format!(
+                    "Colony {} extinct (t={})",
+                    colony.id + 1,
+                    colony.extinct_at.unwrap_or(0)
+                ),
+                Style::default().fg(Color::DarkGray),
+            ));
+            continue;
+        }
+
         let pop = colony.population_summary(world);
         let color = colony.color;
 
+        let status = match colony.status {
+            ColonyStatus::Queenless => Span::styled(" no queen", Style::default().fg(Color::Yellow)),
+            ColonyStatus::Collapsing => Span::styled(" collapsing", Style::default().fg(Color::Red)),
+            _ => Span::raw(""),
+        };
         lines.push(Line::from(vec![
             Span::styled(
                 format!("Colony {} ", colony.id + 1),
                 Style::default().fg(color),
             ),
             Span::raw(format!("Pop: {}", pop.total())),
+            status,
         ]));
+        lines.push(Line::styled(
+            format!(" {}", species.get(colony.species).name),
+            Style::default().fg(Color::Gray),
+        ));
         lines.push(Line::from(vec![
             Span::raw(" Q:"),
             Span::raw(format!("{} ", pop.queens)),
             Span::raw("W:"),
             Span::raw(format!("{} ", pop.workers)),
+            Span::styled(
+                format!("({}/{}/{}) ", pop.minors, pop.workers - pop.minors - pop.majors, pop.majors),
+                Style::default().fg(Color::Gray),
+            ),
             Span::raw("S:"),
             Span::raw(format!("{}", pop.soldiers)),
         ]));
+        // Eggs laid by each of the colony's queens
+        let laid: Vec<String> = world
+            .query::<(&Ant, &ColonyMember, Option<&QueenStats>)>()
+            .iter()
+            .filter(|(_, (ant, member, _))| ant.role == AntRole::Queen && member.colony_id == colony.id)
+            .map(|(_, (_, _, stats))| stats.map(|s| s.eggs_laid).unwrap_or(0).to_string())
+            .collect();
+        if !laid.is_empty() {
+            lines.push(Line::raw(format!(" Laid: {} Rate:{:.1}", laid.join("/"), colony.lay_rate)));
+        }
         lines.push(Line::from(vec![
             Span::raw(" E:"),
             Span::raw(format!("{} ", pop.eggs)),
             Span::raw("L:"),
             Span::raw(format!("{} ", pop.larvae)),
+            Span::raw("P:"),
+            Span::raw(format!("{} ", pop.pupae)),
             Span::raw("Food:"),
             Span::styled(
                 format!("{}", colony.food_stored),
                 Style::default().fg(Color::Green),
             ),
         ]));
+        lines.push(Line::from(vec![
+            Span::raw(" Died:"),
+            Span::raw(format!("{} ", colony.deaths.total())),
+            Span::raw("Starved:"),
+            Span::styled(
+                format!("{} ", colony.deaths.starved),
+                Style::default().fg(Color::Red),
+            ),
+            Span::raw("Brood:"),
+            Span::styled(
+                format!("{}", colony.deaths.neglected),
+                Style::default().fg(Color::Yellow),
+            ),
+        ]));
+        // Food held by repletes
+        let (repletes, reserve) = world
+            .query::<(&ColonyMember, &Replete)>()
+            .iter()
+            .filter(|(_, (member, _))| member.colony_id == colony.id)
+            .fold((0, 0), |(n, food), (_, (_, r))| (n + 1, food + r.stored));
+        if repletes > 0 {
+            lines.push(Line::from(vec![
+                Span::raw(format!(" Repletes:{} ", repletes)),
+                Span::raw("Reserve:"),
+                Span::styled(format!("{}", reserve), Style::default().fg(Color::Green)),
+            ]));
+        }
+        // Fungus garden, for farming species
+        let garden = world
+            .query::<&FungusGarden>()
+            .iter()
+            .find(|(_, g)| g.colony_id == colony.id)
+            .map(|(_, g)| (g.mass, g.diseased));
+        if let Some((mass, diseased)) = garden {
+            let mut spans = vec![Span::raw(format!(" Fungus:{:.0}", mass))];
+            if mass == 0.0 {
+                spans.push(Span::styled(" withered", Style::default().fg(Color::DarkGray)));
+            } else if diseased {
+                spans.push(Span::styled(" diseased", Style::default().fg(Color::Magenta)));
+            }
+            lines.push(Line::from(spans));
+        }
     }
 
     let paragraph = Paragraph::new(lines);
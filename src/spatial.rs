@@ -1,5 +1,8 @@
 use hecs::Entity;
 
+/// Colony id used for entities that belong to no colony (e.g. food sources)
+pub const NO_COLONY: u8 = u8::MAX;
+
 /// Spatial hash grid for O(1) neighbor lookups.
 /// Divides the map into cells of `cell_size` tiles each.
 /// Rebuilt from scratch each tick (O(N) rebuild, O(K) query).
@@ -61,4 +64,21 @@ impl SpatialGrid {
         }
         results
     }
+
+    /// Query all entities in cells overlapping the square of `radius` tiles around (x, y).
+    /// Callers still filter by exact distance.
+    pub fn query_radius(&self, x: i32, y: i32, radius: i32) -> Vec<(Entity, i32, i32, u8)> {
+        let min_cx = ((x - radius).max(0) / self.cell_size) as usize;
+        let min_cy = ((y - radius).max(0) / self.cell_size) as usize;
+        let max_cx = (((x + radius).max(0) / self.cell_size) as usize).min(self.width - 1);
+        let max_cy = (((y + radius).max(0) / self.cell_size) as usize).min(self.height - 1);
+
+        let mut results = Vec::new();
+        for cy in min_cy..=max_cy {
+            for cx in min_cx..=max_cx {
+                results.extend_from_slice(&self.cells[cy * self.width + cx]);
+            }
+        }
+        results
+    }
 }
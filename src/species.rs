@@ -0,0 +1,216 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+
+use crate::colony::ColonyState;
+use crate::config::{CombatConfig, LifecycleConfig};
+
+/// Species data compiled into the binary; the same file under the working
+/// directory replaces it at startup when present.
+pub const SPECIES_FILE: &str = "data/species.ini";
+const BUILTIN_SPECIES: &str = include_str!("../data/species.ini");
+
+/// Traits that set one ant species apart. Strengths and lifespans left unset
+/// fall back to the colony's config; multipliers default to 1.0.
+#[derive(Clone, Debug)]
+pub struct Species {
+    pub key: String,
+    pub name: String,
+    pub worker_strength: Option<u8>,
+    pub soldier_strength: Option<u8>,
+    pub worker_lifespan: Option<u32>,
+    pub soldier_lifespan: Option<u32>,
+    pub queen_lifespan: Option<u32>,
+    pub dig_rate: f32,
+    pub forage: f32,   // food from food sources
+    pub honeydew: f32, // food from tended aphids; 0 never tends them
+    pub aggression: f32,
+    pub fungus: bool, // foragers bring leaves to a fungus garden instead of food
+}
+
+impl Species {
+    fn new(key: &str) -> Self {
+        Self {
+            key: key.to_string(),
+            name: key.to_string(),
+            worker_strength: None,
+            soldier_strength: None,
+            worker_lifespan: None,
+            soldier_lifespan: None,
+            queen_lifespan: None,
+            dig_rate: 1.0,
+            forage: 1.0,
+            honeydew: 1.0,
+            aggression: 1.0,
+            fungus: false,
+        }
+    }
+
+    fn set(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "name" => self.name = value.to_string(),
+            "worker_strength" => self.worker_strength = Some(value.parse()?),
+            "soldier_strength" => self.soldier_strength = Some(value.parse()?),
+            "worker_lifespan" => self.worker_lifespan = Some(value.parse()?),
+            "soldier_lifespan" => self.soldier_lifespan = Some(value.parse()?),
+            "queen_lifespan" => self.queen_lifespan = Some(value.parse()?),
+            "dig_rate" => self.dig_rate = value.parse()?,
+            "forage" => self.forage = value.parse()?,
+            "honeydew" => self.honeydew = value.parse()?,
+            "aggression" => self.aggression = value.parse()?,
+            "fungus" => self.fungus = value.parse()?,
+            _ => bail!("unknown trait `{key}`"),
+        }
+        Ok(())
+    }
+
+    pub fn worker_strength(&self, combat: &CombatConfig) -> u8 {
+        self.worker_strength.unwrap_or(combat.worker_strength)
+    }
+
+    pub fn soldier_strength(&self, combat: &CombatConfig) -> u8 {
+        self.soldier_strength.unwrap_or(combat.soldier_strength)
+    }
+
+    pub fn worker_lifespan(&self, lifecycle: &LifecycleConfig) -> u32 {
+        self.worker_lifespan.unwrap_or(lifecycle.worker_lifespan)
+    }
+
+    pub fn soldier_lifespan(&self, lifecycle: &LifecycleConfig) -> u32 {
+        self.soldier_lifespan.unwrap_or(lifecycle.soldier_lifespan)
+    }
+
+    pub fn queen_lifespan(&self, lifecycle: &LifecycleConfig) -> u32 {
+        self.queen_lifespan.unwrap_or(lifecycle.queen_lifespan)
+    }
+
+    /// Scale a u8 chance threshold by a trait multiplier
+    pub fn scale_chance(chance: u8, factor: f32) -> u8 {
+        (chance as f32 * factor).round().clamp(0.0, 255.0) as u8
+    }
+}
+
+/// Every species colonies can belong to, in file order. Never empty.
+#[derive(Clone, Debug)]
+pub struct SpeciesRegistry {
+    species: Vec<Species>,
+}
+
+impl SpeciesRegistry {
+    /// The species compiled into the binary
+    pub fn builtin() -> Self {
+        Self::parse(BUILTIN_SPECIES).expect("built-in species data is valid")
+    }
+
+    /// Read species from a data file, or keep the built-in set if it is missing
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Self::builtin());
+        }
+        let text = fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        Self::parse(&text).with_context(|| format!("parsing {}", path.display()))
+    }
+
+    /// Parse `[key]` sections of `trait = value` lines; `#` starts a comment
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut species: Vec<Species> = Vec::new();
+
+        for (number, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(key) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                let key = key.trim();
+                if species.iter().any(|s| s.key == key) {
+                    bail!("line {}: species `{key}` defined twice", number + 1);
+                }
+                species.push(Species::new(key));
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                bail!("line {}: expected `trait = value`", number + 1);
+            };
+            let Some(current) = species.last_mut() else {
+                bail!("line {}: trait outside a [species] section", number + 1);
+            };
+            current
+                .set(key.trim(), value.trim())
+                .with_context(|| format!("line {}", number + 1))?;
+        }
+
+        if species.is_empty() {
+            bail!("no species defined");
+        }
+        Ok(Self { species })
+    }
+
+    pub fn get(&self, index: usize) -> &Species {
+        &self.species[index.min(self.species.len() - 1)]
+    }
+
+    /// Species index for the nth colony spawned at startup
+    pub fn for_spawn(&self, colony_index: usize) -> usize {
+        colony_index % self.species.len()
+    }
+
+    /// The species a colony belongs to; unknown colonies get the first one
+    pub fn of_colony(&self, colonies: &[ColonyState], colony_id: u8) -> &Species {
+        self.get(colonies.get(colony_id as usize).map(|c| c.species).unwrap_or(0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_species_parse() {
+        let registry = SpeciesRegistry::builtin();
+        assert_eq!(registry.get(0).key, "leafcutter");
+        assert!(registry.get(0).fungus);
+        assert_eq!(registry.get(2).honeydew, 0.0);
+    }
+
+    #[test]
+    fn unset_traits_fall_back() {
+        let registry = SpeciesRegistry::parse("[plain]\n# only a name\nname = Plain ant\n").unwrap();
+        let plain = registry.get(0);
+        assert_eq!(plain.name, "Plain ant");
+        assert_eq!(plain.worker_strength, None);
+        assert_eq!(plain.forage, 1.0);
+        assert!(!plain.fungus);
+    }
+
+    #[test]
+    fn duplicate_section_is_an_error() {
+        let err = SpeciesRegistry::parse("[fire]\n[army]\n[fire]\n").unwrap_err();
+        assert!(err.to_string().contains("line 3"));
+    }
+
+    #[test]
+    fn unknown_trait_is_an_error() {
+        assert!(SpeciesRegistry::parse("[fire]\nvenom = 3\n").is_err());
+    }
+
+    #[test]
+    fn bad_value_is_an_error() {
+        assert!(SpeciesRegistry::parse("[fire]\nworker_strength = strong\n").is_err());
+    }
+
+    #[test]
+    fn trait_before_section_is_an_error() {
+        let err = SpeciesRegistry::parse("forage = 2.0\n[fire]\n").unwrap_err();
+        assert!(err.to_string().contains("outside"));
+    }
+
+    #[test]
+    fn empty_file_is_an_error() {
+        assert!(SpeciesRegistry::parse("").is_err());
+        assert!(SpeciesRegistry::parse("# comments only\n\n").is_err());
+    }
+}
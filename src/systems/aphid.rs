@@ -46,10 +46,11 @@ pub fn spawn_aphids(world: &mut World, terrain: &Terrain, count: usize, config:
 
 /// Aphid farming system - ants near aphids claim and farm them
 pub fn aphid_system(world: &mut World, colonies: &mut [ColonyState], config: &SimConfig) {
-    // Collect ant positions by colony
+    // Collect ant positions by colony; species that don't drink honeydew ignore aphids
     let mut ant_positions: Vec<(i32, i32, u8)> = Vec::new();
     for (_entity, (pos, ant, member)) in world.query::<(&Position, &Ant, &ColonyMember)>().iter() {
-        if matches!(ant.role, AntRole::Worker | AntRole::Soldier) {
+        let honeydew = config.species.of_colony(colonies, member.colony_id).honeydew;
+        if matches!(ant.role, AntRole::Worker | AntRole::Soldier) && honeydew > 0.0 {
             ant_positions.push((pos.x, pos.y, member.colony_id));
         }
     }
@@ -60,13 +61,14 @@ pub fn aphid_system(world: &mut World, colonies: &mut [ColonyState], config: &Si
 
     for (entity, (pos, aphid)) in world.query::<(&Position, &Aphid)>().iter() {
         // Find nearby ants by colony
-        let mut nearby_counts: [u32; 6] = [0; 6];
+        let mut nearby_counts: Vec<u32> = vec![0; colonies.len()];
 
         for (ax, ay, colony_id) in &ant_positions {
             let dist = (pos.x - ax).abs() + (pos.y - ay).abs();
             if dist <= config.spawn.aphid_nearby_distance {
-                let idx = (*colony_id as usize).min(5);
-                nearby_counts[idx] += 1;
+                if let Some(count) = nearby_counts.get_mut(*colony_id as usize) {
+                    *count += 1;
+                }
             }
         }
 
@@ -106,7 +108,8 @@ pub fn aphid_system(world: &mut World, colonies: &mut [ColonyState], config: &Si
         let idx = colony_id as usize;
         if idx < colonies.len() {
             // Accumulate fractional food
-            colonies[idx].food_stored += amount as u32;
+            let honeydew = config.species.get(colonies[idx].species).honeydew;
+            colonies[idx].food_stored += (amount * honeydew) as u32;
         }
     }
 }
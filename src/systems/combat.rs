@@ -1,12 +1,20 @@
 use hecs::World;
 
+use crate::colony::{ColonyState, DeathCause};
 use crate::components::{Ant, AntRole, AntState, ColonyMember, Dead, Fighter, Position};
-use crate::config::SimConfig;
+use crate::config::{CombatConfig, SimConfig, WorkerSize};
 use crate::spatial::SpatialGrid;
 use crate::systems::pheromone::{PheromoneGrid, PheromoneType};
 
 /// Combat system - ants from different colonies fight when adjacent
-pub fn combat_system(world: &mut World, pheromones: &mut PheromoneGrid, tick: u64, spatial_grid: &SpatialGrid, config: &SimConfig) {
+pub fn combat_system(
+    world: &mut World,
+    pheromones: &mut PheromoneGrid,
+    tick: u64,
+    spatial_grid: &SpatialGrid,
+    colonies: &[ColonyState],
+    config: &SimConfig,
+) {
     if tick % config.combat.combat_interval != 0 {
         return;
     }
@@ -14,24 +22,32 @@ pub fn combat_system(world: &mut World, pheromones: &mut PheromoneGrid, tick: u6
     // Collect all combatant positions
     let mut combatants: Vec<(hecs::Entity, i32, i32, u8, AntRole, u8)> = Vec::new(); // entity, x, y, colony, role, strength
 
-    for (entity, (pos, ant, member)) in world.query::<(&Position, &Ant, &ColonyMember)>().iter() {
+    for (entity, (pos, ant, member, size)) in
+        world.query::<(&Position, &Ant, &ColonyMember, Option<&WorkerSize>)>().iter()
+    {
         // Only workers and soldiers fight
         if !matches!(ant.role, AntRole::Worker | AntRole::Soldier) {
             continue;
         }
 
+        let combat = config.combat_for(member.colony_id);
+        let species = config.species.of_colony(colonies, member.colony_id);
         let strength = match ant.role {
-            AntRole::Soldier => config.combat.soldier_strength,
-            AntRole::Worker => config.combat.worker_strength,
-            _ => config.combat.other_strength,
+            AntRole::Soldier => species.soldier_strength(combat),
+            AntRole::Worker => {
+                let scale = config.worker_sizes.traits(size).strength;
+                (species.worker_strength(combat) as f32 * scale).round().min(255.0) as u8
+            }
+            _ => combat.other_strength,
         };
 
         combatants.push((entity, pos.x, pos.y, member.colony_id, ant.role, strength));
     }
 
     // Find adjacent enemies using spatial grid and resolve combat
-    let mut damage_to_apply: Vec<(hecs::Entity, u8, u8)> = Vec::new(); // entity, damage, attacker_colony
+    let mut damage_to_apply: Vec<(hecs::Entity, u8, u8)> = Vec::new(); // entity, damage, victim colony
     let mut danger_deposits: Vec<(i32, i32, u8)> = Vec::new();
+    let mut recruit_deposits: Vec<(i32, i32, u8)> = Vec::new();
     let mut processed_pairs: Vec<(hecs::Entity, hecs::Entity)> = Vec::new();
 
     for &(entity_a, x_a, y_a, colony_a, role_a, strength_a) in &combatants {
@@ -60,48 +76,60 @@ pub fn combat_system(world: &mut World, pheromones: &mut PheromoneGrid, tick: u6
             // Find entity_b's combat stats from combatants list
             if let Some(&(_, _, _, _, role_b, strength_b)) = combatants.iter().find(|(e, _, _, _, _, _)| *e == entity_b) {
                 // Combat! Each deals damage to the other
-                let damage_a = calculate_damage(strength_a, role_a, config);
-                let damage_b = calculate_damage(strength_b, role_b, config);
+                let damage_a = calculate_damage(strength_a, role_a, config.combat_for(colony_a));
+                let damage_b = calculate_damage(strength_b, role_b, config.combat_for(colony_b));
 
-                damage_to_apply.push((entity_b, damage_a, colony_a));
-                damage_to_apply.push((entity_a, damage_b, colony_b));
+                damage_to_apply.push((entity_b, damage_a, colony_b));
+                damage_to_apply.push((entity_a, damage_b, colony_a));
 
                 // Deposit danger pheromones
                 danger_deposits.push((x_a, y_a, colony_a));
                 danger_deposits.push((x_b, y_b, colony_b));
 
+                // Soldiers in a fight call nestmates in
+                if role_a == AntRole::Soldier {
+                    recruit_deposits.push((x_a, y_a, colony_a));
+                }
+                if role_b == AntRole::Soldier {
+                    recruit_deposits.push((x_b, y_b, colony_b));
+                }
+
                 processed_pairs.push(pair);
             }
         }
     }
 
     // Apply damage
-    for (entity, damage, _attacker_colony) in damage_to_apply {
-        apply_damage(world, entity, damage, config);
+    for (entity, damage, colony) in damage_to_apply {
+        apply_damage(world, entity, damage, config.combat_for(colony));
     }
 
     // Deposit danger pheromones
     for (x, y, colony) in danger_deposits {
-        pheromones.deposit(x, y, colony, PheromoneType::Danger, config.combat.danger_deposit_amount);
+        pheromones.deposit(x, y, colony, PheromoneType::Danger, config.combat_for(colony).danger_deposit_amount);
+    }
+
+    for (x, y, colony) in recruit_deposits {
+        pheromones.deposit(x, y, colony, PheromoneType::Recruit, config.pheromone_for(colony).deposit_recruit);
     }
 }
 
 /// Calculate damage dealt
-fn calculate_damage(strength: u8, role: AntRole, config: &SimConfig) -> u8 {
+fn calculate_damage(strength: u8, role: AntRole, combat: &CombatConfig) -> u8 {
     let base = match role {
-        AntRole::Soldier => config.combat.base_damage * 2,
-        AntRole::Worker => config.combat.base_damage,
-        _ => config.combat.base_damage / 2,
+        AntRole::Soldier => combat.base_damage * 2,
+        AntRole::Worker => combat.base_damage,
+        _ => combat.base_damage / 2,
     };
 
     // Add randomness and strength bonus
-    let roll = fastrand::u8(0..config.combat.damage_random_range);
+    let roll = fastrand::u8(0..combat.damage_random_range);
     let strength_bonus = strength / 10;
     base.saturating_add(roll).saturating_add(strength_bonus).saturating_sub(5)
 }
 
 /// Apply damage to an ant
-fn apply_damage(world: &mut World, entity: hecs::Entity, damage: u8, config: &SimConfig) {
+fn apply_damage(world: &mut World, entity: hecs::Entity, damage: u8, combat: &CombatConfig) {
     // Check if entity has Fighter component
     let current_health = world
         .get::<&Fighter>(entity)
@@ -112,21 +140,21 @@ fn apply_damage(world: &mut World, entity: hecs::Entity, damage: u8, config: &Si
         Some(health) => {
             let new_health = health.saturating_sub(damage);
             if new_health == 0 {
-                let _ = world.insert_one(entity, Dead);
+                let _ = world.insert(entity, (Dead, DeathCause::Combat));
             } else if let Ok(mut fighter) = world.get::<&mut Fighter>(entity) {
                 fighter.health = new_health;
             }
         }
         None => {
             // Add Fighter component with default health
-            let health = config.combat.default_health.saturating_sub(damage);
+            let health = combat.default_health.saturating_sub(damage);
             if health == 0 {
-                let _ = world.insert_one(entity, Dead);
+                let _ = world.insert(entity, (Dead, DeathCause::Combat));
             } else {
                 let _ = world.insert_one(
                     entity,
                     Fighter {
-                        strength: config.combat.default_fighter_strength,
+                        strength: combat.default_fighter_strength,
                         health,
                     },
                 );
@@ -135,8 +163,9 @@ fn apply_damage(world: &mut World, entity: hecs::Entity, damage: u8, config: &Si
     }
 }
 
-/// Soldiers patrol and respond to danger pheromones
-pub fn soldier_ai_system(world: &mut World, pheromones: &PheromoneGrid, config: &SimConfig) {
+/// Soldiers patrol and respond to danger pheromones; aggressive species answer
+/// fainter alarms
+pub fn soldier_ai_system(world: &mut World, pheromones: &PheromoneGrid, colonies: &[ColonyState], config: &SimConfig) {
     let mut state_changes: Vec<(hecs::Entity, AntState)> = Vec::new();
 
     for (entity, (pos, ant, member)) in world.query::<(&Position, &Ant, &ColonyMember)>().iter() {
@@ -147,10 +176,12 @@ pub fn soldier_ai_system(world: &mut World, pheromones: &PheromoneGrid, config:
         // Check for danger pheromones
         let danger = pheromones.get(pos.x, pos.y, member.colony_id, PheromoneType::Danger);
 
-        if danger > config.combat.fight_danger_threshold && ant.state != AntState::Fighting {
+        let combat = config.combat_for(member.colony_id);
+        let aggression = config.species.of_colony(colonies, member.colony_id).aggression.max(0.1);
+        if danger > combat.fight_danger_threshold / aggression && ant.state != AntState::Fighting {
             // Move toward danger
             state_changes.push((entity, AntState::Fighting));
-        } else if danger < config.combat.stop_fight_threshold && ant.state == AntState::Fighting {
+        } else if danger < combat.stop_fight_threshold && ant.state == AntState::Fighting {
             // Return to wandering
             state_changes.push((entity, AntState::Wandering));
         }
@@ -163,24 +194,23 @@ pub fn soldier_ai_system(world: &mut World, pheromones: &PheromoneGrid, config:
     }
 }
 
-/// Workers flee from enemies
-pub fn flee_system(world: &mut World, pheromones: &PheromoneGrid, config: &SimConfig) {
+/// Workers flee from enemies; aggressive species hold their ground longer
+pub fn flee_system(world: &mut World, pheromones: &PheromoneGrid, colonies: &[ColonyState], config: &SimConfig) {
     let mut state_changes: Vec<(hecs::Entity, AntState)> = Vec::new();
 
-    for (entity, (pos, ant, _member)) in world.query::<(&Position, &Ant, &ColonyMember)>().iter() {
+    for (entity, (pos, ant, member)) in world.query::<(&Position, &Ant, &ColonyMember)>().iter() {
         if ant.role != AntRole::Worker {
             continue;
         }
 
         // Check for danger pheromones (from any colony - means combat)
-        let mut danger = 0.0f32;
-        for c in 0..config.combat.max_colonies_scan {
-            danger = danger.max(pheromones.get(pos.x, pos.y, c, PheromoneType::Danger));
-        }
+        let danger = pheromones.max_any(pos.x, pos.y, PheromoneType::Danger);
 
-        if danger > config.combat.flee_danger_threshold && ant.state != AntState::Fleeing && ant.state != AntState::Carrying {
+        let combat = config.combat_for(member.colony_id);
+        let aggression = config.species.of_colony(colonies, member.colony_id).aggression;
+        if danger > combat.flee_danger_threshold * aggression && ant.state != AntState::Fleeing && ant.state != AntState::Carrying {
             state_changes.push((entity, AntState::Fleeing));
-        } else if danger < config.combat.stop_flee_threshold && ant.state == AntState::Fleeing {
+        } else if danger < combat.stop_flee_threshold && ant.state == AntState::Fleeing {
             state_changes.push((entity, AntState::Wandering));
         }
     }
@@ -203,7 +233,7 @@ pub fn fighting_movement(
 }
 
 /// Movement for fleeing workers - move away from danger
-pub fn fleeing_movement(pos: &Position, pheromones: &PheromoneGrid, config: &SimConfig) -> Option<(i32, i32)> {
+pub fn fleeing_movement(pos: &Position, pheromones: &PheromoneGrid) -> Option<(i32, i32)> {
     // Find direction with least danger
     let directions = [
         (0, -1),
@@ -220,16 +250,10 @@ pub fn fleeing_movement(pos: &Position, pheromones: &PheromoneGrid, config: &Sim
     let mut min_danger = f32::MAX;
 
     // Sum danger from all colonies at current position
-    let mut current_danger = 0.0f32;
-    for c in 0..config.combat.max_colonies_scan {
-        current_danger += pheromones.get(pos.x, pos.y, c, PheromoneType::Danger);
-    }
+    let current_danger = pheromones.sum_any(pos.x, pos.y, PheromoneType::Danger);
 
     for (dx, dy) in directions {
-        let mut danger = 0.0f32;
-        for c in 0..config.combat.max_colonies_scan {
-            danger += pheromones.get(pos.x + dx, pos.y + dy, c, PheromoneType::Danger);
-        }
+        let danger = pheromones.sum_any(pos.x + dx, pos.y + dy, PheromoneType::Danger);
 
         if danger < min_danger && danger < current_danger {
             min_danger = danger;
@@ -1,15 +1,28 @@
 use hecs::World;
 
+use crate::colony::ColonyState;
 use crate::components::{Ant, AntRole, AntState, ColonyMember, Position};
-use crate::config::SimConfig;
+use crate::config::{MovementConfig, SimConfig, WorkerSize};
+use crate::flow_field::FlowField;
+use crate::species::Species;
+use crate::systems::food::Replete;
+use crate::systems::lifecycle::Nurse;
 use crate::terrain::{Terrain, TerrainType};
 
 /// Process digging actions for ants in Digging state
-pub fn dig_system(world: &mut World, terrain: &mut Terrain, config: &SimConfig) {
+pub fn dig_system(
+    world: &mut World,
+    terrain: &mut Terrain,
+    flow_field: &mut FlowField,
+    colonies: &[ColonyState],
+    config: &SimConfig,
+) {
     // Collect dig actions
-    let mut digs: Vec<(i32, i32)> = Vec::new();
+    let mut digs: Vec<(i32, i32, u8)> = Vec::new(); // x, y, colony
 
-    for (_entity, (pos, ant, _member)) in world.query::<(&Position, &Ant, &ColonyMember)>().iter() {
+    for (_entity, (pos, ant, member, size)) in
+        world.query::<(&Position, &Ant, &ColonyMember, Option<&WorkerSize>)>().iter()
+    {
         // Only workers can dig
         if ant.role != AntRole::Worker {
             continue;
@@ -20,8 +33,12 @@ pub fn dig_system(world: &mut World, terrain: &mut Terrain, config: &SimConfig)
             continue;
         }
 
-        // Slow down digging - only dig occasionally
-        if fastrand::u8(..) >= config.movement.dig_chance {
+        // Slow down digging - only dig occasionally, at the species' pace and
+        // the digger's size
+        let dig_rate = config.species.of_colony(colonies, member.colony_id).dig_rate
+            * config.worker_sizes.traits(size).dig;
+        let dig_chance = config.movement_for(member.colony_id).dig_chance;
+        if fastrand::u8(..) >= Species::scale_chance(dig_chance, dig_rate) {
             continue;
         }
 
@@ -36,24 +53,25 @@ pub fn dig_system(world: &mut World, terrain: &mut Terrain, config: &SimConfig)
 
         for (tx, ty) in dig_targets {
             if terrain.is_diggable(tx, ty) {
-                digs.push((tx, ty));
+                digs.push((tx, ty, member.colony_id));
                 break;
             }
         }
     }
 
     // Apply digs and reinforce tunnels
-    for (x, y) in digs {
+    for (x, y, colony_id) in digs {
         // Dig creates a tunnel (reinforced passage that won't collapse)
         terrain.set(x, y, TerrainType::Tunnel);
+        flow_field.mark_changed(x, y);
 
         // Ants reinforce adjacent soil walls to prevent cave-ins
-        reinforce_adjacent(terrain, x, y, config);
+        reinforce_adjacent(terrain, x, y, config.movement_for(colony_id));
     }
 }
 
 /// Reinforce adjacent soil tiles to prevent cave-ins
-fn reinforce_adjacent(terrain: &mut Terrain, x: i32, y: i32, config: &SimConfig) {
+fn reinforce_adjacent(terrain: &mut Terrain, x: i32, y: i32, movement: &MovementConfig) {
     let neighbors = [
         (x - 1, y),     // left
         (x + 1, y),     // right
@@ -64,7 +82,7 @@ fn reinforce_adjacent(terrain: &mut Terrain, x: i32, y: i32, config: &SimConfig)
 
     for (nx, ny) in neighbors {
         // Only reinforce soil that's adjacent to tunnels
-        if terrain.is_diggable(nx, ny) && fastrand::u8(..) < config.movement.reinforce_chance {
+        if terrain.is_diggable(nx, ny) && fastrand::u8(..) < movement.reinforce_chance {
             // Mark as dense soil (more stable)
             terrain.set(nx, ny, TerrainType::SoilDense);
         }
@@ -77,8 +95,11 @@ pub fn dig_ai_system(world: &mut World, terrain: &Terrain, config: &SimConfig) {
     let mut state_changes: Vec<(hecs::Entity, AntState)> = Vec::new();
 
     for (entity, (pos, ant, member)) in world.query::<(&Position, &Ant, &ColonyMember)>().iter() {
-        // Only workers
-        if ant.role != AntRole::Worker {
+        // Only workers, and not while they are tending brood or hanging in the larder
+        if ant.role != AntRole::Worker
+            || world.get::<&Nurse>(entity).is_ok()
+            || world.get::<&Replete>(entity).is_ok()
+        {
             continue;
         }
 
@@ -100,10 +121,12 @@ pub fn dig_ai_system(world: &mut World, terrain: &Terrain, config: &SimConfig) {
 fn decide_worker_state(
     pos: &Position,
     ant: &Ant,
-    _member: &ColonyMember,
+    member: &ColonyMember,
     terrain: &Terrain,
     config: &SimConfig,
 ) -> AntState {
+    let movement = config.movement_for(member.colony_id);
+
     // Check if there's diggable terrain nearby (below or to sides)
     let can_dig_down = terrain.is_diggable(pos.x, pos.y + 1);
     let can_dig_left = terrain.is_diggable(pos.x - 1, pos.y);
@@ -125,7 +148,7 @@ fn decide_worker_state(
     match ant.state {
         AntState::Wandering => {
             // Moderate chance to start digging (~19.5%) -- ants wander ~5 ticks before digging
-            if can_dig && on_ground && fastrand::u8(..) < config.movement.start_dig_chance {
+            if can_dig && on_ground && fastrand::u8(..) < movement.start_dig_chance {
                 AntState::Digging
             } else {
                 AntState::Wandering
@@ -135,7 +158,7 @@ fn decide_worker_state(
             // Keep digging if we can, otherwise go back to wandering
             if can_dig {
                 // Chance to stop and return to surface increases with depth
-                let return_chance = if is_underground { config.movement.underground_return_chance } else { config.movement.surface_return_chance };
+                let return_chance = if is_underground { movement.underground_return_chance } else { movement.surface_return_chance };
                 if fastrand::u8(..) < return_chance {
                     AntState::Returning
                 } else {
@@ -151,7 +174,7 @@ fn decide_worker_state(
             if is_on_surface {
                 // Arrived at surface, start wandering again
                 AntState::Wandering
-            } else if can_dig && on_ground && fastrand::u8(..) < config.movement.dig_distraction_chance {
+            } else if can_dig && on_ground && fastrand::u8(..) < movement.dig_distraction_chance {
                 // Sometimes get distracted and dig again
                 AntState::Digging
             } else {
@@ -160,7 +183,7 @@ fn decide_worker_state(
         }
         AntState::Idle => {
             // Start wandering (low chance -- movement.rs owns this transition at ~35%)
-            if fastrand::u8(..) < config.movement.idle_to_wander_chance_dig {
+            if fastrand::u8(..) < movement.idle_to_wander_chance_dig {
                 AntState::Wandering
             } else {
                 AntState::Idle
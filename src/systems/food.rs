@@ -1,10 +1,79 @@
 use hecs::World;
 
-use crate::colony::ColonyState;
+use crate::colony::{ColonyState, ColonyStatus};
 use crate::components::{Ant, AntRole, AntState, CarryItem, Carrying, ColonyMember, FoodSource, Position};
-use crate::config::SimConfig;
+use crate::config::{SimConfig, WorkerSize};
+use crate::flow_field::FlowField;
+use crate::spatial::SpatialGrid;
+use crate::systems::lifecycle::{Brood, Nurse, Satiety};
+use crate::systems::movement::Heading;
 use crate::systems::pheromone::{PheromoneGrid, PheromoneType};
-use crate::terrain::Terrain;
+use crate::systems::water::WaterGrid;
+use crate::terrain::{Terrain, TerrainType};
+
+/// Forager that picked up from a rich food source and will recruit nestmates
+/// once it has delivered its load
+pub struct Recruiter {
+    pub food_x: i32,
+    pub food_y: i32,
+    pub recruits: u8,
+}
+
+/// Leader of a tandem run back to a food source
+pub struct TandemLeader {
+    pub target_x: i32,
+    pub target_y: i32,
+    pub followers: Vec<hecs::Entity>,
+    pub ticks: u16,
+}
+
+/// Nestmate tracking a specific tandem leader
+pub struct TandemFollower {
+    pub leader: hecs::Entity,
+    pub lost_ticks: u16,
+}
+
+/// What a forager remembers about its last productive food site
+pub struct AntMemory {
+    pub food_site: Option<(i32, i32)>,
+    pub site_depleted: bool,
+    pub ticks_since_visit: u32,
+}
+
+/// Liquid food held in an ant's social stomach, shared with nestmates on contact
+pub struct Crop {
+    pub food: u16,
+}
+
+/// Leaf material on its way to the colony's fungus garden, carried as a
+/// regular load
+pub struct Leaf {
+    pub amount: f32,
+}
+
+/// Fungus garden grown on leaf material in a farming colony's nursery chamber.
+/// Gardens carry no `Ant` component.
+pub struct FungusGarden {
+    pub colony_id: u8,
+    pub mass: f32,      // fungus; the harvest scales with it
+    pub substrate: f32, // leaf material not yet broken down
+    pub diseased: bool,
+    pub harvest: f32, // food grown but not yet added to the stores
+}
+
+/// Honeypot replete: a worker hanging in the larder that holds surplus food in
+/// its swollen crop until the colony runs short
+pub struct Replete {
+    pub stored: u32,
+}
+
+/// A recent mouth-to-mouth feeding between two ants, kept for the sharing overlay
+#[derive(Clone, Copy, Debug)]
+pub struct SharingLink {
+    pub from: (i32, i32),
+    pub to: (i32, i32),
+    pub tick: u64,
+}
 
 /// Spawn food sources on the surface
 pub fn spawn_food_sources(world: &mut World, terrain: &Terrain, count: usize, config: &SimConfig) {
@@ -46,17 +115,18 @@ pub fn food_regrow_system(world: &mut World, tick: u64, config: &SimConfig) {
 
     for (_entity, food) in world.query::<&mut FoodSource>().iter() {
         if food.amount < config.food.initial_amount {
-            food.amount = food.amount.saturating_add(food.regrow_rate as u16);
+            food.amount = food.amount.saturating_add(food.regrow_rate as u16).min(config.food.initial_amount);
         }
     }
 }
 
-/// Workers forage for food
+/// Workers forage for food. Each load goes down into the forager's crop; it
+/// reaches the colony through trophallaxis on the way home and at the nest.
 pub fn foraging_system(
     world: &mut World,
     _terrain: &Terrain,
     _pheromones: &PheromoneGrid,
-    colonies: &mut [ColonyState],
+    colonies: &[ColonyState],
     config: &SimConfig,
 ) {
     // Collect food source positions and amounts
@@ -68,73 +138,109 @@ pub fn foraging_system(
     }
 
     // Find ants that can pick up food
-    let mut pickups: Vec<(hecs::Entity, hecs::Entity)> = Vec::new(); // (ant, food)
-    let mut deposits: Vec<(u8, u8)> = Vec::new(); // (colony_id, amount)
+    let mut pickups: Vec<(hecs::Entity, hecs::Entity, i32, i32, f32)> = Vec::new(); // (ant, food, x, y, carry)
+    let mut empty_visits: Vec<hecs::Entity> = Vec::new();
 
-    for (ant_entity, (pos, ant, member)) in
-        world.query::<(&Position, &Ant, &ColonyMember)>().iter()
+    for (ant_entity, (pos, ant, memory, size)) in world
+        .query::<(&Position, &Ant, Option<&AntMemory>, Option<&WorkerSize>)>()
+        .iter()
     {
-        if ant.role != AntRole::Worker {
+        if ant.role != AntRole::Worker || ant.state != AntState::Wandering {
             continue;
         }
+        let carry = config.worker_sizes.traits(size).carry;
 
-        match ant.state {
-            AntState::Wandering => {
-                // Check if at food source
-                for (fx, fy, food_entity) in &food_positions {
-                    if pos.x == *fx && pos.y == *fy {
-                        pickups.push((ant_entity, *food_entity));
-                        break;
-                    }
-                }
-            }
-            AntState::Carrying => {
-                // Check if at home (near colony home position)
-                let colony_id = member.colony_id as usize;
-                if colony_id < colonies.len() {
-                    let home_x = colonies[colony_id].home_x;
-                    let home_y = colonies[colony_id].home_y;
-                    let dist = (pos.x - home_x).abs() + (pos.y - home_y).abs();
-                    if dist <= config.food.deposit_distance {
-                        deposits.push((member.colony_id, config.food.food_per_deposit));
-                    }
-                }
+        // Check if at food source
+        let mut found = false;
+        for (fx, fy, food_entity) in &food_positions {
+            if pos.x == *fx && pos.y == *fy {
+                pickups.push((ant_entity, *food_entity, *fx, *fy, carry));
+                found = true;
+                break;
             }
-            _ => {}
+        }
+
+        // Back at the remembered site and nothing left -- note it's dry
+        if !found && memory.and_then(|m| m.food_site) == Some((pos.x, pos.y)) {
+            empty_visits.push(ant_entity);
         }
     }
 
     // Process pickups
-    for (ant_entity, food_entity) in pickups {
+    for (ant_entity, food_entity, fx, fy, carry) in pickups {
         // Check food amount first
-        let has_food = world
+        let amount = world
             .get::<&FoodSource>(food_entity)
-            .map(|f| f.amount > 0)
-            .unwrap_or(false);
+            .map(|f| f.amount)
+            .unwrap_or(0);
 
-        if has_food {
-            // Reduce food amount
+        if amount > 0 {
+            // Take a full load, scaled by body size, or whatever is left
+            let full = (config.food.food_per_pickup as f32 * carry).round().min(255.0) as u16;
+            let load = full.min(amount) as u8;
+            let remaining = amount - load as u16;
             if let Ok(mut food) = world.get::<&mut FoodSource>(food_entity) {
-                food.amount -= 1;
+                food.amount = remaining;
             }
 
+            // Remember the site so the ant comes back after delivering
+            let _ = world.insert_one(
+                ant_entity,
+                AntMemory {
+                    food_site: Some((fx, fy)),
+                    site_depleted: remaining == 0,
+                    ticks_since_visit: 0,
+                },
+            );
+
             // Change ant state to carrying
             if let Ok(mut ant) = world.get::<&mut Ant>(ant_entity) {
                 ant.state = AntState::Carrying;
             }
-            let _ = world.insert_one(ant_entity, Carrying { item: CarryItem::Food(config.food.food_per_pickup) });
+            let _ = world.insert_one(ant_entity, Carrying { item: CarryItem::Food(load) });
+
+            let colony_id = world.get::<&ColonyMember>(ant_entity).map(|m| m.colony_id).unwrap_or(0);
+            let species = config.species.of_colony(colonies, colony_id);
+            if species.fungus {
+                // Fungus farmers cut leaves for their garden instead; leaves
+                // only feed the colony once the fungus has grown on them
+                let _ = world.insert_one(ant_entity, Leaf { amount: load as f32 * species.forage });
+            } else {
+                // Swallow the load, scaled by how much the species gets out of forage
+                let food = ((load as f32 * species.forage).round() as u16).min(config.food.crop_capacity);
+                let _ = world.insert_one(ant_entity, Crop { food });
+            }
+
+            // Rich sources are worth recruiting for -- more food, more recruits
+            if amount >= config.recruitment.rich_food_threshold {
+                let recruits = (amount / config.recruitment.food_per_recruit.max(1))
+                    .min(config.recruitment.max_recruits as u16) as u8;
+                let _ = world.insert_one(ant_entity, Recruiter { food_x: fx, food_y: fy, recruits });
+            }
         }
     }
 
-    // Process deposits
-    for (colony_id, amount) in deposits {
-        let colony_id = colony_id as usize;
-        if colony_id < colonies.len() {
-            colonies[colony_id].food_stored += amount as u32;
+    for entity in empty_visits {
+        if let Ok(mut memory) = world.get::<&mut AntMemory>(entity) {
+            memory.site_depleted = true;
+            memory.ticks_since_visit = 0;
         }
     }
 
-    // Carrying state reset is handled in check_deposit function
+    // Unloading at the nest and the Carrying reset happen in trophallaxis_system
+    // and check_deposit
+}
+
+/// The world a forager finds its way through, shared by every ant in a
+/// movement pass
+#[derive(Clone, Copy)]
+pub struct ForageContext<'a> {
+    pub terrain: &'a Terrain,
+    pub pheromones: &'a PheromoneGrid,
+    pub flow_field: &'a FlowField,
+    pub food_grid: &'a SpatialGrid,
+    pub colonies: &'a [ColonyState],
+    pub config: &'a SimConfig,
 }
 
 /// Movement AI for foraging ants
@@ -142,13 +248,29 @@ pub fn foraging_movement(
     pos: &Position,
     ant: &Ant,
     member: &ColonyMember,
-    terrain: &Terrain,
-    pheromones: &PheromoneGrid,
-    colonies: &[ColonyState],
-    config: &SimConfig,
+    heading: Option<Heading>,
+    memory: Option<&AntMemory>,
+    ctx: &ForageContext,
 ) -> Option<(i32, i32)> {
+    let ForageContext { terrain, pheromones, flow_field, food_grid, colonies, config } = *ctx;
     match ant.state {
         AntState::Wandering => {
+            // Head straight for food the ant can see or smell
+            if ant.role == AntRole::Worker {
+                if let Some(dir) = food_sensing_movement(pos, terrain, food_grid, config) {
+                    return Some(dir);
+                }
+            }
+
+            // Return to the last productive site if it wasn't stripped bare
+            if let Some(AntMemory { food_site: Some((sx, sy)), site_depleted: false, .. }) = memory {
+                if fastrand::u8(..) < config.food.memory_follow_chance {
+                    if let Some(dir) = direct_step(pos, *sx, *sy, terrain) {
+                        return Some(dir);
+                    }
+                }
+            }
+
             // Follow food pheromones if strong enough
             if let Some(dir) =
                 crate::systems::pheromone::follow_pheromone(
@@ -157,6 +279,7 @@ pub fn foraging_movement(
                     pos.y,
                     member.colony_id,
                     PheromoneType::Food,
+                    heading,
                     terrain,
                 )
             {
@@ -167,28 +290,22 @@ pub fn foraging_movement(
             None // Use default random movement
         }
         AntState::Carrying => {
-            // Move toward home using home pheromones or direct path
+            // Follow the nest distance field through tunnels when it reaches us
+            if fastrand::u8(..) < config.movement_for(member.colony_id).flow_follow_chance {
+                if let Some(dir) = flow_field.nest_step(member.colony_id, pos.x, pos.y) {
+                    return Some(dir);
+                }
+            }
+
+            // Otherwise move toward home using home pheromones or direct path
             let colony_id = member.colony_id as usize;
             if colony_id < colonies.len() {
                 let home_x = colonies[colony_id].home_x;
                 let home_y = colonies[colony_id].home_y;
 
                 // Try to move toward home
-                let dx = (home_x - pos.x).signum();
-                let dy = (home_y - pos.y).signum();
-
-                if dx != 0 || dy != 0 {
-                    // Prefer direct path if passable
-                    if terrain.is_passable(pos.x + dx, pos.y + dy) {
-                        return Some((dx, dy));
-                    }
-                    // Try just horizontal or vertical
-                    if dx != 0 && terrain.is_passable(pos.x + dx, pos.y) {
-                        return Some((dx, 0));
-                    }
-                    if dy != 0 && terrain.is_passable(pos.x, pos.y + dy) {
-                        return Some((0, dy));
-                    }
+                if let Some(dir) = direct_step(pos, home_x, home_y, terrain) {
+                    return Some(dir);
                 }
 
                 // Fall back to home pheromones
@@ -198,6 +315,7 @@ pub fn foraging_movement(
                     pos.y,
                     member.colony_id,
                     PheromoneType::Home,
+                    heading,
                     terrain,
                 ) {
                     return Some(dir);
@@ -209,14 +327,128 @@ pub fn foraging_movement(
     }
 }
 
-/// Check if ant has deposited food and should stop carrying
+/// Steer toward the nearest food source within the sensing radius that
+/// isn't hidden behind solid terrain
+fn food_sensing_movement(
+    pos: &Position,
+    terrain: &Terrain,
+    food_grid: &SpatialGrid,
+    config: &SimConfig,
+) -> Option<(i32, i32)> {
+    let radius = config.food.sense_radius;
+
+    let nearest = food_grid
+        .query_radius(pos.x, pos.y, radius)
+        .into_iter()
+        .map(|(_, fx, fy, _)| (fx, fy, (fx - pos.x).abs().max((fy - pos.y).abs())))
+        .filter(|&(_, _, dist)| dist > 0 && dist <= radius)
+        .filter(|&(fx, fy, _)| has_line_of_sight(terrain, pos.x, pos.y, fx, fy))
+        .min_by_key(|&(_, _, dist)| dist)?;
+
+    direct_step(pos, nearest.0, nearest.1, terrain)
+}
+
+/// True if every tile on the straight line between two points is passable
+fn has_line_of_sight(terrain: &Terrain, x0: i32, y0: i32, x1: i32, y1: i32) -> bool {
+    // Bresenham walk, skipping the start tile
+    let dx = (x1 - x0).abs();
+    let dy = -(y1 - y0).abs();
+    let sx = (x1 - x0).signum();
+    let sy = (y1 - y0).signum();
+    let mut err = dx + dy;
+    let (mut x, mut y) = (x0, y0);
+
+    while (x, y) != (x1, y1) {
+        let e2 = 2 * err;
+        if e2 >= dy {
+            err += dy;
+            x += sx;
+        }
+        if e2 <= dx {
+            err += dx;
+            y += sy;
+        }
+        if !terrain.is_passable(x, y) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Step straight toward a target, sliding along one axis if the diagonal is blocked
+pub fn direct_step(pos: &Position, target_x: i32, target_y: i32, terrain: &Terrain) -> Option<(i32, i32)> {
+    let dx = (target_x - pos.x).signum();
+    let dy = (target_y - pos.y).signum();
+
+    if dx == 0 && dy == 0 {
+        return None;
+    }
+
+    // Prefer direct path if passable
+    if terrain.is_passable(pos.x + dx, pos.y + dy) {
+        return Some((dx, dy));
+    }
+    // Try just horizontal or vertical
+    if dx != 0 && terrain.is_passable(pos.x + dx, pos.y) {
+        return Some((dx, 0));
+    }
+    if dy != 0 && terrain.is_passable(pos.x, pos.y + dy) {
+        return Some((0, dy));
+    }
+    None
+}
+
+/// Movement for ants in a tandem run: leaders head for the food source but
+/// pause for stragglers, followers close in on their leader
+pub fn tandem_movement(
+    entity: hecs::Entity,
+    pos: &Position,
+    world: &World,
+    terrain: &Terrain,
+    config: &SimConfig,
+) -> Option<(i32, i32)> {
+    if let Ok(leader) = world.get::<&TandemLeader>(entity) {
+        // Wait until every follower has caught up
+        let straggling = leader.followers.iter().any(|follower| {
+            world
+                .get::<&Position>(*follower)
+                .map(|f| chebyshev(pos, f.x, f.y) > config.recruitment.contact_distance)
+                .unwrap_or(false)
+        });
+        if straggling {
+            return Some((0, 0));
+        }
+        return direct_step(pos, leader.target_x, leader.target_y, terrain);
+    }
+
+    if let Ok(follower) = world.get::<&TandemFollower>(entity) {
+        let leader_pos = world.get::<&Position>(follower.leader).ok()?;
+        if chebyshev(pos, leader_pos.x, leader_pos.y) <= 1 {
+            return Some((0, 0)); // In contact, let the leader set the pace
+        }
+        return direct_step(pos, leader_pos.x, leader_pos.y, terrain);
+    }
+
+    None
+}
+
+fn chebyshev(pos: &Position, x: i32, y: i32) -> i32 {
+    (pos.x - x).abs().max((pos.y - y).abs())
+}
+
+/// Foragers back at the nest stop carrying once their crop is empty
 pub fn check_deposit(world: &mut World, colonies: &[ColonyState], config: &SimConfig) {
     let mut to_stop_carrying: Vec<hecs::Entity> = Vec::new();
 
     for (entity, (pos, ant, member, _carrying)) in
         world.query::<(&Position, &Ant, &ColonyMember, &Carrying)>().iter()
     {
-        if ant.state != AntState::Carrying {
+        // Leaf carriers keep going down to the garden
+        if ant.state != AntState::Carrying || world.get::<&Leaf>(entity).is_ok() {
+            continue;
+        }
+        // Still handing food out to nestmates
+        if world.get::<&Crop>(entity).is_ok_and(|c| c.food > 0) {
             continue;
         }
 
@@ -232,9 +464,658 @@ pub fn check_deposit(world: &mut World, colonies: &[ColonyState], config: &SimCo
     }
 
     for entity in to_stop_carrying {
-        if let Ok(mut ant) = world.get::<&mut Ant>(entity) {
+        let _ = world.remove_one::<Carrying>(entity);
+        unload_recruiter(world, entity, config);
+    }
+}
+
+/// A forager that just unloaded goes back to wandering, unless it came back
+/// from a rich source and leads nestmates out to it
+fn unload_recruiter(world: &mut World, entity: hecs::Entity, config: &SimConfig) {
+    let recruiter = world.remove_one::<Recruiter>(entity).ok();
+
+    if let Ok(mut ant) = world.get::<&mut Ant>(entity) {
+        ant.state = if recruiter.is_some() {
+            AntState::Following
+        } else {
+            AntState::Wandering
+        };
+    }
+
+    if let Some(recruiter) = recruiter {
+        let _ = world.insert_one(
+            entity,
+            TandemLeader {
+                target_x: recruiter.food_x,
+                target_y: recruiter.food_y,
+                followers: Vec::with_capacity(recruiter.recruits as usize),
+                ticks: 0,
+            },
+        );
+        recruit_followers(world, entity, recruiter.recruits, config);
+    }
+}
+
+/// Pick up to `count` idle or wandering nestmates near the leader as followers.
+/// A leader that finds nobody goes back to wandering.
+fn recruit_followers(
+    world: &mut World,
+    leader: hecs::Entity,
+    count: u8,
+    config: &SimConfig,
+) {
+    let (lx, ly, colony_id) = match (world.get::<&Position>(leader), world.get::<&ColonyMember>(leader)) {
+        (Ok(pos), Ok(member)) => (pos.x, pos.y, member.colony_id),
+        _ => return,
+    };
+
+    let mut recruits: Vec<hecs::Entity> = Vec::new();
+    for (entity, (pos, ant, member)) in world.query::<(&Position, &Ant, &ColonyMember)>().iter() {
+        if recruits.len() >= count as usize {
+            break;
+        }
+        if entity == leader
+            || member.colony_id != colony_id
+            || ant.role != AntRole::Worker
+            || !matches!(ant.state, AntState::Idle | AntState::Wandering)
+            || world.get::<&Nurse>(entity).is_ok()
+            || world.get::<&Replete>(entity).is_ok()
+        {
+            continue;
+        }
+        let dist = (pos.x - lx).abs().max((pos.y - ly).abs());
+        if dist <= config.recruitment.recruit_radius {
+            recruits.push(entity);
+        }
+    }
+
+    if recruits.is_empty() {
+        if let Ok(mut ant) = world.get::<&mut Ant>(leader) {
             ant.state = AntState::Wandering;
         }
-        let _ = world.remove_one::<Carrying>(entity);
+        let _ = world.remove_one::<TandemLeader>(leader);
+        return;
+    }
+
+    for &follower in &recruits {
+        if let Ok(mut ant) = world.get::<&mut Ant>(follower) {
+            ant.state = AntState::Following;
+        }
+        let _ = world.insert_one(follower, TandemFollower { leader, lost_ticks: 0 });
+    }
+    if let Ok(mut tandem) = world.get::<&mut TandemLeader>(leader) {
+        tandem.followers = recruits;
+    }
+}
+
+/// Maintain tandem runs: release everyone once the leader arrives, gives up or
+/// loses all followers, and let followers that lose contact fall back to wandering
+pub fn tandem_system(world: &mut World, config: &SimConfig) {
+    let mut released: Vec<hecs::Entity> = Vec::new();
+
+    for (entity, (pos, ant, leader)) in world.query::<(&Position, &Ant, &mut TandemLeader)>().iter() {
+        let arrived = chebyshev(pos, leader.target_x, leader.target_y) <= 1;
+        let gave_up = ant.state != AntState::Following
+            || leader.ticks >= config.recruitment.leader_patience_ticks;
+        let deserted = leader.followers.iter().all(|f| {
+            world
+                .get::<&TandemFollower>(*f)
+                .map(|t| t.leader != entity)
+                .unwrap_or(true)
+        });
+
+        if arrived || gave_up || deserted {
+            released.push(entity);
+            released.extend(leader.followers.iter().copied());
+        } else {
+            leader.ticks += 1;
+        }
+    }
+
+    for (entity, (pos, ant, follower)) in
+        world.query::<(&Position, &Ant, &mut TandemFollower)>().iter()
+    {
+        if ant.state != AntState::Following {
+            released.push(entity);
+            continue;
+        }
+
+        let leader_pos = if world.get::<&TandemLeader>(follower.leader).is_ok() {
+            world.get::<&Position>(follower.leader).ok().map(|p| (p.x, p.y))
+        } else {
+            None
+        };
+        let in_contact = leader_pos
+            .map(|(lx, ly)| chebyshev(pos, lx, ly) <= config.recruitment.lost_contact_distance)
+            .unwrap_or(false);
+
+        if in_contact {
+            follower.lost_ticks = 0;
+        } else {
+            follower.lost_ticks += 1;
+            if follower.lost_ticks >= config.recruitment.lost_contact_ticks {
+                released.push(entity);
+            }
+        }
+    }
+
+    for entity in released {
+        if let Ok(mut ant) = world.get::<&mut Ant>(entity) {
+            if ant.state == AntState::Following {
+                ant.state = AntState::Wandering;
+            }
+        }
+        let _ = world.remove_one::<TandemLeader>(entity);
+        let _ = world.remove_one::<TandemFollower>(entity);
+    }
+}
+
+/// Age food-site memories and forget sites not visited for too long; sites
+/// last found empty are forgotten sooner
+pub fn memory_decay_system(world: &mut World, config: &SimConfig) {
+    for (_entity, memory) in world.query::<&mut AntMemory>().iter() {
+        if memory.food_site.is_none() {
+            continue;
+        }
+        memory.ticks_since_visit += 1;
+        let limit = if memory.site_depleted { config.food.depleted_memory_ticks } else { config.food.memory_ticks };
+        if memory.ticks_since_visit > limit {
+            memory.food_site = None;
+            memory.site_depleted = false;
+        }
+    }
+}
+
+/// Trophallaxis: ants with food in their crop feed hungry nestmates they touch,
+/// and top up the crops of emptier workers so food spreads through the nest.
+/// Foragers back at the nest unload into the stores through whichever
+/// nestmates they meet. Returns the feedings that happened this tick.
+pub fn trophallaxis_system(
+    world: &mut World,
+    spatial_grid: &SpatialGrid,
+    colonies: &mut [ColonyState],
+    tick: u64,
+    config: &SimConfig,
+) -> Vec<SharingLink> {
+    let mut links: Vec<SharingLink> = Vec::new();
+    if tick % config.food.share_interval != 0 {
+        return links;
+    }
+
+    let donors: Vec<(hecs::Entity, i32, i32, u8, bool)> = world
+        .query::<(&Position, &Ant, &ColonyMember, &Crop)>()
+        .iter()
+        .filter(|(_, (_, _, _, crop))| crop.food > 0)
+        .map(|(entity, (pos, ant, member, _))| {
+            let unloading = ant.state == AntState::Carrying
+                && colonies.get(member.colony_id as usize).is_some_and(|c| {
+                    (pos.x - c.home_x).abs() + (pos.y - c.home_y).abs() <= config.food.deposit_distance
+                });
+            (entity, pos.x, pos.y, member.colony_id, unloading)
+        })
+        .collect();
+
+    for (donor, x, y, colony_id, unloading) in donors {
+        let mut available = world.get::<&Crop>(donor).map(|c| c.food).unwrap_or(0);
+
+        for (other, _, _, other_colony) in spatial_grid.query_nearby(x, y) {
+            if available == 0 {
+                break;
+            }
+            if other == donor || other_colony != colony_id {
+                continue;
+            }
+
+            // Mouths must touch
+            let Ok((ox, oy)) = world.get::<&Position>(other).map(|p| (p.x, p.y)) else { continue };
+            if (ox - x).abs().max((oy - y).abs()) > 1 {
+                continue;
+            }
+
+            let amount = config.food.share_amount.min(available);
+            let lifecycle = config.lifecycle_for(colony_id);
+            let hungry = world
+                .get::<&Satiety>(other)
+                .map(|s| s.value < lifecycle.hungry_threshold)
+                .unwrap_or(false);
+
+            let passed = if hungry {
+                if let Ok(mut satiety) = world.get::<&mut Satiety>(other) {
+                    let gain = amount.saturating_mul(config.food.satiety_per_food);
+                    satiety.value = satiety.value.saturating_add(gain).min(lifecycle.satiety_max);
+                }
+                // Whatever a larva swallows counts toward its caste
+                if let Ok(mut brood) = world.get::<&mut Brood>(other) {
+                    brood.nutrition = brood.nutrition.saturating_add(amount);
+                }
+                amount
+            } else if unloading {
+                // A forager home from the field passes its load on for storage
+                let adult = world
+                    .get::<&Ant>(other)
+                    .map(|a| matches!(a.role, AntRole::Queen | AntRole::Worker | AntRole::Soldier))
+                    .unwrap_or(false);
+                let Some(colony) = colonies.get_mut(colony_id as usize).filter(|_| adult) else { continue };
+                colony.food_stored += amount as u32;
+                amount
+            } else {
+                // Sated workers take a sip into their own crop if theirs is emptier
+                let is_worker = world
+                    .get::<&Ant>(other)
+                    .map(|a| a.role == AntRole::Worker)
+                    .unwrap_or(false);
+                let other_crop = world.get::<&Crop>(other).map(|c| c.food).unwrap_or(0);
+                if !is_worker || other_crop + amount >= available {
+                    continue;
+                }
+                // A crop can't hold more than its capacity; the rest stays with the donor
+                let filled = (other_crop + amount).min(config.food.crop_capacity);
+                if filled <= other_crop {
+                    continue;
+                }
+                let _ = world.insert_one(other, Crop { food: filled });
+                filled - other_crop
+            };
+
+            available -= passed;
+            links.push(SharingLink { from: (x, y), to: (ox, oy), tick });
+        }
+
+        if let Ok(mut crop) = world.get::<&mut Crop>(donor) {
+            crop.food = available;
+        }
+    }
+
+    links
+}
+
+/// Fungus farming. Farming colonies keep a garden in their nursery chamber and
+/// leaf carriers unload into it. Every garden interval the fungus breaks the
+/// leaves down and grows, and tended gardens feed the colony. Untended or
+/// flooded gardens catch disease and wither until workers weed them; a garden
+/// that withers away stays dead in its chamber until fresh leaves regrow it.
+pub fn fungus_garden_system(
+    world: &mut World,
+    water: &WaterGrid,
+    colonies: &mut [ColonyState],
+    spatial_grid: &SpatialGrid,
+    tick: u64,
+    config: &SimConfig,
+) {
+    let fungus = &config.fungus;
+
+    // Plant a garden in each farming colony's nursery and keep it there when
+    // the nursery moves; gardens of colonies that died out go with them
+    let mut gardens: Vec<Option<hecs::Entity>> = vec![None; colonies.len()];
+    let mut orphaned: Vec<hecs::Entity> = Vec::new();
+    for (entity, garden) in world.query::<&FungusGarden>().iter() {
+        match gardens.get_mut(garden.colony_id as usize) {
+            Some(slot) => *slot = Some(entity),
+            None => orphaned.push(entity),
+        }
+    }
+    for entity in orphaned {
+        let _ = world.despawn(entity);
+    }
+    for (colony, garden) in colonies.iter().zip(&gardens) {
+        let farms = colony.status != ColonyStatus::Extinct && config.species.get(colony.species).fungus;
+        match (*garden, colony.nursery) {
+            (Some(entity), _) if !farms => {
+                let _ = world.despawn(entity);
+            }
+            (Some(entity), Some((x, y))) => {
+                if let Ok(mut pos) = world.get::<&mut Position>(entity) {
+                    pos.x = x;
+                    pos.y = y;
+                }
+            }
+            (None, Some((x, y))) if farms => {
+                world.spawn((
+                    Position { x, y },
+                    FungusGarden {
+                        colony_id: colony.id,
+                        mass: fungus.seed_mass,
+                        substrate: 0.0,
+                        diseased: false,
+                        harvest: 0.0,
+                    },
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    // Leaf carriers unload into their garden; with no garden yet the leaves
+    // are dropped at the nest
+    let sites: Vec<(hecs::Entity, i32, i32, u8)> = world
+        .query::<(&Position, &FungusGarden)>()
+        .iter()
+        .map(|(entity, (pos, garden))| (entity, pos.x, pos.y, garden.colony_id))
+        .collect();
+    let mut unloaded: Vec<(hecs::Entity, Option<hecs::Entity>, f32)> = Vec::new(); // ant, garden, leaves
+    for (entity, (pos, member, leaf)) in world.query::<(&Position, &ColonyMember, &Leaf)>().iter() {
+        let garden = sites.iter().find(|&&(_, _, _, c)| c == member.colony_id);
+        match garden {
+            Some(&(garden, gx, gy, _)) => {
+                if (pos.x - gx).abs().max((pos.y - gy).abs()) <= 1 {
+                    unloaded.push((entity, Some(garden), leaf.amount));
+                }
+            }
+            None => {
+                let at_home = colonies.get(member.colony_id as usize).is_some_and(|c| {
+                    (pos.x - c.home_x).abs() + (pos.y - c.home_y).abs() <= config.food.deposit_distance
+                });
+                if at_home {
+                    unloaded.push((entity, None, 0.0));
+                }
+            }
+        }
+    }
+    for (ant, garden, leaves) in unloaded {
+        if let Some(Ok(mut garden)) = garden.map(|g| world.get::<&mut FungusGarden>(g)) {
+            garden.substrate += leaves;
+        }
+        let _ = world.remove_one::<Leaf>(ant);
+        let _ = world.remove_one::<Carrying>(ant);
+        unload_recruiter(world, ant, config);
+    }
+
+    if tick % fungus.garden_interval != 0 {
+        return;
+    }
+
+    for (_entity, (pos, garden)) in world.query::<(&Position, &mut FungusGarden)>().iter() {
+        let tenders = spatial_grid
+            .query_radius(pos.x, pos.y, fungus.tend_radius)
+            .into_iter()
+            .filter(|&(e, _, _, c)| {
+                c == garden.colony_id
+                    && world.get::<&Ant>(e).map(|a| a.role == AntRole::Worker).unwrap_or(false)
+            })
+            .count();
+        let flooded = water.depth(pos.x, pos.y) > 0;
+
+        if garden.diseased {
+            // Disease eats the fungus until enough workers weed it out
+            garden.mass *= 1.0 - fungus.disease_loss;
+            let cure_chance = (fungus.weed_chance as usize * tenders).min(u8::MAX as usize) as u8;
+            if !flooded && fastrand::u8(..) < cure_chance {
+                garden.diseased = false;
+            }
+        } else {
+            let disease_chance = if tenders == 0 { fungus.untended_disease_chance } else { fungus.disease_chance };
+            garden.diseased = flooded || fastrand::u8(..) < disease_chance;
+
+            // The fungus grows on fresh leaves and shrinks without them
+            let digested = garden.substrate.min(fungus.digest_rate);
+            garden.substrate -= digested;
+            if digested > 0.0 {
+                garden.mass = (garden.mass + digested * fungus.leaf_to_mass).min(fungus.max_mass);
+            } else {
+                garden.mass *= 1.0 - fungus.starve_loss;
+            }
+
+            // Only tended gardens get harvested
+            if tenders > 0 {
+                garden.harvest += garden.mass * fungus.food_per_mass;
+            }
+        }
+
+        if let Some(colony) = colonies.get_mut(garden.colony_id as usize) {
+            let food = garden.harvest as u32;
+            colony.food_stored += food;
+            garden.harvest -= food as f32;
+        }
+        // A withered garden is lost; only new leaves grow it back
+        if garden.mass < 1.0 {
+            garden.mass = 0.0;
+        }
+    }
+}
+
+/// Honeypot repletes. Each colony keeps a larder chamber deep below the nest.
+/// While the stores run over, a worker there becomes a replete and soaks up
+/// the surplus; once the stores run short the repletes feed it back. Raiders
+/// that corner a replete carry it off, food and all, to their own colony.
+#[allow(clippy::too_many_arguments)]
+pub fn replete_system(
+    world: &mut World,
+    terrain: &Terrain,
+    water: &WaterGrid,
+    colonies: &mut [ColonyState],
+    flow_field: &mut FlowField,
+    spatial_grid: &SpatialGrid,
+    tick: u64,
+    config: &SimConfig,
+) {
+    let repletes = &config.repletes;
+
+    // A flooding larder gets replaced right away rather than at the next check
+    let flooded = colonies.iter().any(|c| c.larder.is_some_and(|(x, y)| water.depth(x, y) > 0));
+    if flooded || tick % repletes.larder_check_interval == 0 {
+        choose_larders(terrain, water, colonies, flow_field, config);
+    }
+
+    if tick % repletes.interval != 0 {
+        return;
+    }
+
+    capture_repletes(world, spatial_grid, config);
+
+    // Repletes in the larder take in the surplus or hand back what they hold
+    let mut held = vec![0u32; colonies.len()]; // repletes per colony
+    let mut hungry = vec![0u32; colonies.len()]; // of those, the ones less than half full
+    for (_entity, (pos, member, replete)) in world.query::<(&Position, &ColonyMember, &mut Replete)>().iter() {
+        let id = member.colony_id as usize;
+        let Some(colony) = colonies.get_mut(id) else { continue };
+        held[id] += 1;
+        if replete.stored < repletes.capacity / 2 {
+            hungry[id] += 1;
+        }
+
+        let housed = colony
+            .larder
+            .is_some_and(|(lx, ly)| (pos.x - lx).abs().max((pos.y - ly).abs()) <= repletes.larder_spread);
+        if !housed {
+            continue;
+        }
+        if colony.food_stored > repletes.surplus {
+            let room = repletes.capacity.saturating_sub(replete.stored);
+            let amount = repletes.fill_rate.min(room).min(colony.food_stored - repletes.surplus);
+            replete.stored += amount;
+            colony.food_stored -= amount;
+        } else if colony.food_stored < repletes.shortage {
+            let amount = repletes.dispense_rate.min(replete.stored);
+            replete.stored -= amount;
+            colony.food_stored += amount;
+        }
+    }
+
+    // Colonies still running over take on another replete once the ones they
+    // have are filling up, picking the free worker closest to the larder
+    let mut workers = vec![0u32; colonies.len()];
+    let mut nearest: Vec<Option<(i32, hecs::Entity)>> = vec![None; colonies.len()];
+    for (entity, (pos, ant, member)) in world.query::<(&Position, &Ant, &ColonyMember)>().iter() {
+        let id = member.colony_id as usize;
+        let Some(colony) = colonies.get(id) else { continue };
+        if ant.role != AntRole::Worker {
+            continue;
+        }
+        workers[id] += 1;
+
+        let Some((lx, ly)) = colony.larder else { continue };
+        let free = matches!(ant.state, AntState::Idle | AntState::Wandering)
+            && world.get::<&Replete>(entity).is_err()
+            && world.get::<&Nurse>(entity).is_err()
+            && world.get::<&Carrying>(entity).is_err();
+        let dist = (pos.x - lx).abs().max((pos.y - ly).abs());
+        if free && nearest[id].map(|(d, _)| dist < d).unwrap_or(true) {
+            nearest[id] = Some((dist, entity));
+        }
+    }
+
+    let mut recruits: Vec<hecs::Entity> = Vec::new();
+    for (id, colony) in colonies.iter().enumerate() {
+        let room = held[id] * 100 < workers[id] * repletes.max_share;
+        if colony.food_stored > repletes.surplus && room && hungry[id] == 0 {
+            recruits.extend(nearest[id].map(|(_, entity)| entity));
+        }
+    }
+    for entity in recruits {
+        let _ = world.insert_one(entity, Replete { stored: 0 });
+        if let Ok(mut ant) = world.get::<&mut Ant>(entity) {
+            ant.state = AntState::Idle;
+        }
+    }
+}
+
+/// Raiders next to a replete drag it off to their own nest. Capture is just a
+/// change of `ColonyMember`: the replete keeps its `Replete` store and crop,
+/// and since population counts and larder reserves are all tallied from
+/// colony membership, the food and the ant move from the victim's books to
+/// the captor's with nothing else to settle. The replete then heads for its
+/// new colony's larder like any other.
+fn capture_repletes(world: &mut World, spatial_grid: &SpatialGrid, config: &SimConfig) {
+    let mut captured: Vec<(hecs::Entity, u8)> = Vec::new();
+    for (entity, (pos, member, _)) in world.query::<(&Position, &ColonyMember, &Replete)>().iter() {
+        let raider = spatial_grid
+            .query_radius(pos.x, pos.y, 1)
+            .into_iter()
+            .filter(|&(e, _, _, c)| {
+                c != member.colony_id
+                    && world
+                        .get::<&Ant>(e)
+                        .map(|a| matches!(a.role, AntRole::Worker | AntRole::Soldier))
+                        .unwrap_or(false)
+            })
+            .find(|_| fastrand::u8(..) < config.repletes.capture_chance);
+        if let Some((_, _, _, colony_id)) = raider {
+            captured.push((entity, colony_id));
+        }
+    }
+    for (entity, colony_id) in captured {
+        if let Ok(mut member) = world.get::<&mut ColonyMember>(entity) {
+            member.colony_id = colony_id;
+        }
+    }
+}
+
+/// Pick a dry tunnel tile deep below each nest as its larder, keeping the
+/// current one for as long as it stays dry and open
+fn choose_larders(
+    terrain: &Terrain,
+    water: &WaterGrid,
+    colonies: &mut [ColonyState],
+    flow_field: &mut FlowField,
+    config: &SimConfig,
+) {
+    let repletes = &config.repletes;
+    for colony in colonies.iter_mut() {
+        let (home_x, home_y, id) = (colony.home_x, colony.home_y, colony.id);
+        let suitable = |x: i32, y: i32| {
+            terrain.get(x, y) == Some(TerrainType::Tunnel)
+                && y - home_y >= repletes.larder_min_depth
+                && water.depth(x, y) == 0
+                && flow_field.nest_distance(id, x, y).is_some()
+        };
+
+        if let Some((x, y)) = colony.larder {
+            if suitable(x, y) {
+                continue;
+            }
+        }
+
+        // Deepest suitable tile, the one nearest the entrance among equals
+        let r = repletes.larder_radius;
+        let mut best: Option<(i32, u16, (i32, i32))> = None;
+        for y in home_y..=home_y + r {
+            for x in home_x - r..=home_x + r {
+                if !suitable(x, y) {
+                    continue;
+                }
+                let Some(d) = flow_field.nest_distance(id, x, y) else { continue };
+                if best.map(|(best_y, best_d, _)| (y, best_d) > (best_y, d)).unwrap_or(true) {
+                    best = Some((y, d, (x, y)));
+                }
+            }
+        }
+
+        let site = best.map(|(_, _, site)| site);
+        if site != colony.larder {
+            colony.larder = site;
+            flow_field.set_larder(id, site, terrain);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_crop_leaves_the_rest_with_the_donor() {
+        let mut config = SimConfig::default();
+        config.food.crop_capacity = 6;
+        config.food.share_amount = 4;
+
+        let mut world = World::new();
+        let worker = |state| Ant { role: AntRole::Worker, state };
+        let donor = world.spawn((
+            Position { x: 5, y: 5 },
+            worker(AntState::Wandering),
+            ColonyMember { colony_id: 0 },
+            Crop { food: 12 },
+        ));
+        let receiver = world.spawn((
+            Position { x: 6, y: 5 },
+            worker(AntState::Idle),
+            ColonyMember { colony_id: 0 },
+            Crop { food: 4 },
+            Satiety { value: config.lifecycle.satiety_max },
+        ));
+
+        let mut grid = SpatialGrid::new(20, 20, 8);
+        grid.insert(donor, 5, 5, 0);
+        grid.insert(receiver, 6, 5, 0);
+        let mut colonies = vec![ColonyState::new(0, 0, 0, 0, 0)];
+
+        trophallaxis_system(&mut world, &grid, &mut colonies, 0, &config);
+
+        // Only the 2 that fit were passed on; the donor still holds the rest
+        assert_eq!(world.get::<&Crop>(receiver).unwrap().food, 6);
+        assert_eq!(world.get::<&Crop>(donor).unwrap().food, 10);
+    }
+
+    #[test]
+    fn captured_replete_joins_the_raiders_with_its_food() {
+        fastrand::seed(1);
+        let mut config = SimConfig::default();
+        config.repletes.capture_chance = 255;
+
+        let mut world = World::new();
+        let replete = world.spawn((
+            Position { x: 5, y: 5 },
+            Ant { role: AntRole::Worker, state: AntState::Idle },
+            ColonyMember { colony_id: 0 },
+            Replete { stored: 40 },
+            Crop { food: 3 },
+        ));
+        let raider = world.spawn((
+            Position { x: 6, y: 5 },
+            Ant { role: AntRole::Soldier, state: AntState::Fighting },
+            ColonyMember { colony_id: 1 },
+        ));
+        let mut grid = SpatialGrid::new(20, 20, 8);
+        grid.insert(replete, 5, 5, 0);
+        grid.insert(raider, 6, 5, 1);
+        let colonies = vec![ColonyState::new(0, 0, 0, 0, 0), ColonyState::new(1, 0, 15, 0, 0)];
+
+        capture_repletes(&mut world, &grid, &config);
+
+        assert_eq!(world.get::<&ColonyMember>(replete).unwrap().colony_id, 1);
+        assert_eq!(world.get::<&Replete>(replete).unwrap().stored, 40);
+        assert_eq!(world.get::<&Crop>(replete).unwrap().food, 3);
+        assert_eq!(colonies[0].population_summary(&world).workers, 0);
+        assert_eq!(colonies[1].population_summary(&world).workers, 1);
     }
 }
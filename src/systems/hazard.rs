@@ -1,13 +1,16 @@
 use hecs::World;
 
-use crate::components::{Dead, Position};
+use crate::colony::{ColonyState, DeathCause};
+use crate::components::{ColonyMember, Dead, Position};
 use crate::config::SimConfig;
+use crate::flow_field::FlowField;
+use crate::systems::food::Replete;
 use crate::terrain::{Terrain, TerrainType};
 
 /// Check for and process cave-ins
 /// A tile is unstable if it's soil with too much air around/below it
 /// Tunnels (ant-reinforced passages) prevent adjacent tiles from collapsing
-pub fn cave_in_system(terrain: &mut Terrain, world: &mut World, config: &SimConfig) {
+pub fn cave_in_system(terrain: &mut Terrain, world: &mut World, flow_field: &mut FlowField, config: &SimConfig) {
     let width = terrain.width as i32;
     let height = terrain.height as i32;
 
@@ -77,6 +80,8 @@ pub fn cave_in_system(terrain: &mut Terrain, world: &mut World, config: &SimConf
             let dirt_type = terrain.get(x, y).unwrap_or(TerrainType::Soil);
             terrain.set(x, y, TerrainType::Air);
             terrain.set(x, land_y, dirt_type);
+            flow_field.mark_changed(x, y);
+            flow_field.mark_changed(x, land_y);
 
             // Kill any ants at the landing spot
             kill_ants_at(world, x, land_y);
@@ -134,12 +139,22 @@ fn kill_ants_at(world: &mut World, x: i32, y: i32) {
 
     for entity in to_kill {
         // Add Dead component to mark for removal
-        let _ = world.insert_one(entity, Dead);
+        let _ = world.insert(entity, (Dead, DeathCause::Crushed));
     }
 }
 
-/// Remove all entities marked as Dead
-pub fn cleanup_dead(world: &mut World) {
+/// Remove all entities marked as Dead, tallying colony deaths by cause.
+/// Food a dead replete held goes back into its colony's stores.
+pub fn cleanup_dead(world: &mut World, colonies: &mut [ColonyState]) {
+    for (_entity, (_dead, cause, member, replete)) in
+        world.query::<(&Dead, &DeathCause, &ColonyMember, Option<&Replete>)>().iter()
+    {
+        if let Some(colony) = colonies.get_mut(member.colony_id as usize) {
+            colony.deaths.record(*cause);
+            colony.food_stored += replete.map(|r| r.stored).unwrap_or(0);
+        }
+    }
+
     let dead: Vec<hecs::Entity> = world.query::<&Dead>().iter().map(|(e, _)| e).collect();
 
     for entity in dead {
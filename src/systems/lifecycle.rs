@@ -1,39 +1,135 @@
-use hecs::World;
+use std::collections::HashSet;
 
-use crate::colony::ColonyState;
-use crate::components::{Age, Ant, AntRole, AntState, ColonyMember, Dead, Position};
-use crate::config::SimConfig;
+use hecs::{Entity, World};
+
+use crate::colony::{ColonyState, ColonyStatus, DeathCause};
+use crate::components::{Age, Ant, AntRole, AntState, Aphid, ColonyMember, Dead, Position};
+use crate::config::{LifecycleConfig, QueenRelations, SimConfig, WorkerSize};
+use crate::flow_field::FlowField;
+use crate::spatial::SpatialGrid;
+use crate::systems::food::Replete;
+use crate::systems::movement::Escaping;
+use crate::systems::water::WaterGrid;
+use crate::terrain::{Terrain, TerrainType};
+
+/// How full an ant's stomach is; drains over time and the ant starves at zero
+pub struct Satiety {
+    pub value: u16,
+}
+
+/// A larva spun into a cocoon: it no longer eats and emerges as an adult
+pub struct Pupa;
+
+/// Care bookkeeping for an egg, larva or pupa
+pub struct Brood {
+    pub neglected: bool,
+    pub neglected_ticks: u32,
+    pub nutrition: u16, // food eaten as a larva; decides the adult caste
+}
+
+/// A larva being reared as a replacement queen
+pub struct Gyne;
+
+/// Male brood, laid by a mature colony's queen or a queenless worker; it
+/// emerges as a male alate
+pub struct Male;
+
+/// Per-queen laying record
+pub struct QueenStats {
+    pub eggs_laid: u32,
+}
+
+/// Winged reproductive waiting in its home nest for the next nuptial flight.
+/// Alates carry no `Ant` component, so the colony's systems leave them be.
+pub struct Alate {
+    pub colony_id: u8,
+    pub female: bool,
+}
+
+/// Marks brood that a nurse is currently carrying
+pub struct CarriedBy(pub Entity);
+
+/// What a nurse is doing for one piece of brood
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NurseTask {
+    /// Walk over to the brood and pick it up
+    Fetch(Entity),
+    /// Carry the brood to the colony's nursery
+    Deliver(Entity),
+    /// Walk over to a hungry larva and feed it from colony stores
+    Feed(Entity),
+}
+
+impl NurseTask {
+    pub fn brood(self) -> Entity {
+        match self {
+            NurseTask::Fetch(e) | NurseTask::Deliver(e) | NurseTask::Feed(e) => e,
+        }
+    }
+}
+
+/// A worker busy tending brood
+pub struct Nurse {
+    pub task: NurseTask,
+    pub ticks: u32,
+}
 
 /// Main lifecycle system - handles aging, hatching, maturing, and death
-pub fn lifecycle_system(world: &mut World, colonies: &mut [ColonyState], tick: u64, config: &SimConfig) {
+pub fn lifecycle_system(
+    world: &mut World,
+    terrain: &Terrain,
+    colonies: &mut [ColonyState],
+    tick: u64,
+    config: &SimConfig,
+) {
     // Process queen egg-laying
-    queen_lay_eggs(world, colonies, tick, config);
+    queen_lay_eggs(world, terrain, colonies, tick, config);
 
     // Process egg hatching
     hatch_eggs(world, tick, config);
 
     // Process larvae maturing
-    mature_larvae(world, tick, config);
+    mature_larvae(world, colonies, tick, config);
+
+    // Process queen loss, succession and colony collapse
+    if tick % config.colony.status_check_interval == 0 {
+        colony_status_system(world, colonies, tick, config);
+    }
 
     // Process aging and natural death
-    age_and_die(world, tick);
+    age_and_die(world, tick, config);
 
-    // Process food consumption
+    // Process hunger, meals and starvation
     if tick % config.lifecycle.food_consume_interval as u64 == 0 {
         consume_food(world, colonies, config);
     }
 }
 
-/// Queens lay eggs if colony has enough food
-fn queen_lay_eggs(world: &mut World, colonies: &mut [ColonyState], tick: u64, config: &SimConfig) {
+/// Queens lay eggs at a rate set by their age, the colony's food reserves,
+/// how much brood the workers already have to look after, and the season
+fn queen_lay_eggs(
+    world: &mut World,
+    terrain: &Terrain,
+    colonies: &mut [ColonyState],
+    tick: u64,
+    config: &SimConfig,
+) {
     if tick % config.lifecycle.queen_lay_interval as u64 != 0 {
         return;
     }
 
     // Collect egg spawn info
     let mut eggs_to_spawn: Vec<(i32, i32, u8)> = Vec::new();
+    let mut layers: Vec<Entity> = Vec::new();
+    let dominant = dominant_queens(world, colonies.len(), config);
+    let conditions: Vec<f32> = colonies.iter().map(|c| laying_conditions(world, c, config)).collect();
+    for colony in colonies.iter_mut() {
+        colony.lay_rate = 0.0;
+    }
 
-    for (_entity, (pos, ant, member)) in world.query::<(&Position, &Ant, &ColonyMember)>().iter() {
+    for (entity, (pos, ant, member, age)) in
+        world.query::<(&Position, &Ant, &ColonyMember, Option<&Age>)>().iter()
+    {
         if ant.role != AntRole::Queen {
             continue;
         }
@@ -43,20 +139,50 @@ fn queen_lay_eggs(world: &mut World, colonies: &mut [ColonyState], tick: u64, co
             continue;
         }
 
+        // Fertility follows age; rivals of a dominant queen are held back
+        let lifecycle = config.lifecycle_for(member.colony_id);
+        let mut fertility = age.map(|a| queen_fertility(a, lifecycle)).unwrap_or(1.0);
+        if config.colony.queen_relations == QueenRelations::Compete
+            && dominant[colony_id].is_some_and(|d| d != entity)
+        {
+            fertility *= config.colony.subordinate_fertility;
+        }
+
+        // Lay the whole eggs of the expected count, plus one more by chance
+        let rate = fertility * conditions[colony_id] * season_factor(tick, lifecycle, config);
+        colonies[colony_id].lay_rate += rate;
+        let eggs = rate as u32 + u32::from(fastrand::f32() < rate.fract());
+
         // Check if colony has enough food
-        if colonies[colony_id].food_stored >= config.lifecycle.food_per_egg {
-            colonies[colony_id].food_stored -= config.lifecycle.food_per_egg;
+        for _ in 0..eggs {
+            if colonies[colony_id].food_stored < lifecycle.food_per_egg {
+                break;
+            }
+            colonies[colony_id].food_stored -= lifecycle.food_per_egg;
             eggs_to_spawn.push((pos.x, pos.y, member.colony_id));
+            layers.push(entity);
         }
     }
 
+    for queen in layers {
+        let laid = world.get::<&QueenStats>(queen).map(|s| s.eggs_laid).unwrap_or(0);
+        let _ = world.insert_one(queen, QueenStats { eggs_laid: laid + 1 });
+    }
+
+    // Mature colonies put some of their eggs toward males for the next flight
+    let mature: Vec<bool> = colonies.iter().map(|c| colony_mature(world, c, config)).collect();
+
     // Spawn eggs near queens
     for (x, y, colony_id) in eggs_to_spawn {
-        // Spawn egg adjacent to queen
+        // Spawn egg on an open tile next to the queen, or under the queen if boxed in
         let offsets = [(0, 1), (1, 0), (-1, 0), (0, -1), (1, 1), (-1, 1)];
-        let (ox, oy) = offsets[fastrand::usize(..offsets.len())];
+        let open: Vec<(i32, i32)> = offsets
+            .into_iter()
+            .filter(|(ox, oy)| terrain.is_passable(x + ox, y + oy))
+            .collect();
+        let (ox, oy) = if open.is_empty() { (0, 0) } else { open[fastrand::usize(..open.len())] };
 
-        world.spawn((
+        let egg = world.spawn((
             Position { x: x + ox, y: y + oy },
             Ant {
                 role: AntRole::Egg,
@@ -65,74 +191,498 @@ fn queen_lay_eggs(world: &mut World, colonies: &mut [ColonyState], tick: u64, co
             ColonyMember { colony_id },
             Age {
                 ticks: 0,
-                max_ticks: config.lifecycle.egg_hatch_time,
+                max_ticks: config.lifecycle_for(colony_id).egg_hatch_time,
             },
         ));
+        if mature[colony_id as usize] && fastrand::u8(..) < config.colony.male_egg_chance {
+            let _ = world.insert_one(egg, Male);
+        }
     }
 }
 
+/// Queen fertility by age: builds up while young, holds at its peak through
+/// mid-life and tails off toward the end of the lifespan
+pub fn queen_fertility(age: &Age, lifecycle: &LifecycleConfig) -> f32 {
+    let life = age.ticks as f32 / age.max_ticks.max(1) as f32;
+    let (prime, decline) = (lifecycle.queen_prime_start, lifecycle.queen_decline_start);
+    let min = lifecycle.queen_min_fertility;
+
+    if life < prime {
+        min + (1.0 - min) * life / prime
+    } else if life > decline {
+        (1.0 - (life - decline) / (1.0 - decline)).max(min)
+    } else {
+        1.0
+    }
+}
+
+/// Colony-wide laying multiplier: rich stores boost laying, scarce ones throttle
+/// it to nothing, and a brood pile too big for the workforce holds it back
+fn laying_conditions(world: &World, colony: &ColonyState, config: &SimConfig) -> f32 {
+    let lifecycle = config.lifecycle_for(colony.id);
+    if colony.status == ColonyStatus::Extinct || colony.food_stored < lifecycle.lay_food_reserve {
+        return 0.0;
+    }
+    let food = (colony.food_stored as f32 / lifecycle.lay_food_comfort.max(1) as f32)
+        .min(lifecycle.lay_max_food_boost);
+
+    let pop = colony.population_summary(world);
+    let brood = (pop.eggs + pop.larvae + pop.pupae) as f32;
+    // A queen without workers rears a small first brood by herself
+    let capacity = if pop.workers == 0 {
+        config.colony.founding_brood as f32
+    } else {
+        pop.workers as f32 * lifecycle.brood_per_worker
+    }
+    .max(1.0);
+    let room = (1.0 - brood / capacity).clamp(0.0, 1.0);
+
+    food * room
+}
+
+/// Yearly laying cycle, one year per nuptial flight interval: lowest right
+/// after a flight, peaking halfway to the next
+fn season_factor(tick: u64, lifecycle: &LifecycleConfig, config: &SimConfig) -> f32 {
+    let year = config.colony.flight_interval.max(1);
+    let phase = (tick % year) as f32 / year as f32;
+    1.0 - lifecycle.lay_season_amplitude * (phase * std::f32::consts::TAU).cos()
+}
+
+/// The most fertile queen of each colony
+fn dominant_queens(world: &World, num_colonies: usize, config: &SimConfig) -> Vec<Option<Entity>> {
+    let mut best: Vec<Option<(Entity, f32)>> = vec![None; num_colonies];
+    for (entity, (ant, member, age)) in world.query::<(&Ant, &ColonyMember, &Age)>().iter() {
+        if ant.role != AntRole::Queen {
+            continue;
+        }
+        let Some(slot) = best.get_mut(member.colony_id as usize) else { continue };
+        let fertility = queen_fertility(age, config.lifecycle_for(member.colony_id));
+        if slot.map(|(_, f)| fertility > f).unwrap_or(true) {
+            *slot = Some((entity, fertility));
+        }
+    }
+    best.into_iter().map(|b| b.map(|(e, _)| e)).collect()
+}
+
+/// Big, well-stocked colonies with a queen rear alates
+fn colony_mature(world: &World, colony: &ColonyState, config: &SimConfig) -> bool {
+    colony.status == ColonyStatus::Thriving
+        && colony.food_stored >= config.colony.alate_food_reserve
+        && colony.population_summary(world).total() >= config.colony.mature_population
+}
+
 /// Eggs hatch into larvae after enough time
 fn hatch_eggs(world: &mut World, _tick: u64, config: &SimConfig) {
-    let mut to_hatch: Vec<hecs::Entity> = Vec::new();
+    let mut to_hatch: Vec<(hecs::Entity, u8)> = Vec::new();
 
-    for (entity, (ant, age)) in world.query::<(&Ant, &Age)>().iter() {
+    for (entity, (ant, member, age)) in world.query::<(&Ant, &ColonyMember, &Age)>().iter() {
         if ant.role == AntRole::Egg && age.ticks >= age.max_ticks {
-            to_hatch.push(entity);
+            to_hatch.push((entity, member.colony_id));
         }
     }
 
-    for entity in to_hatch {
+    for (entity, colony_id) in to_hatch {
         if let Ok(mut ant) = world.get::<&mut Ant>(entity) {
             ant.role = AntRole::Larvae;
         }
         if let Ok(mut age) = world.get::<&mut Age>(entity) {
             age.ticks = 0;
-            age.max_ticks = config.lifecycle.larvae_mature_time;
+            age.max_ticks = config.lifecycle_for(colony_id).larvae_mature_time;
         }
     }
 }
 
-/// Larvae mature into workers or soldiers
-fn mature_larvae(world: &mut World, _tick: u64, config: &SimConfig) {
+/// Larvae spin cocoons, and pupae emerge as workers or soldiers
+fn mature_larvae(world: &mut World, colonies: &mut [ColonyState], _tick: u64, config: &SimConfig) {
+    let mut to_pupate: Vec<(hecs::Entity, u8)> = Vec::new();
     let mut to_mature: Vec<hecs::Entity> = Vec::new();
 
-    for (entity, (ant, age)) in world.query::<(&Ant, &Age)>().iter() {
+    for (entity, (ant, member, age, pupa)) in
+        world.query::<(&Ant, &ColonyMember, &Age, Option<&Pupa>)>().iter()
+    {
         if ant.role == AntRole::Larvae && age.ticks >= age.max_ticks {
-            to_mature.push(entity);
+            if pupa.is_some() {
+                to_mature.push(entity);
+            } else {
+                to_pupate.push((entity, member.colony_id));
+            }
+        }
+    }
+
+    for (entity, colony_id) in to_pupate {
+        let _ = world.insert_one(entity, Pupa);
+        let _ = world.remove_one::<Satiety>(entity);
+        if let Ok(mut age) = world.get::<&mut Age>(entity) {
+            age.ticks = 0;
+            age.max_ticks = config.lifecycle_for(colony_id).pupa_time;
         }
     }
 
+    if to_mature.is_empty() {
+        return;
+    }
+
+    // Colonies stop raising soldiers once they make up enough of the adults
+    let army_full: Vec<bool> = colonies
+        .iter()
+        .map(|c| {
+            let pop = c.population_summary(world);
+            let adults = (pop.workers + pop.soldiers).max(1) as f32;
+            pop.soldiers as f32 / adults >= config.lifecycle_for(c.id).max_soldier_fraction
+        })
+        .collect();
+    let mature: Vec<bool> = colonies.iter().map(|c| colony_mature(world, c, config)).collect();
+
+    let mut alates: Vec<(hecs::Entity, usize, bool)> = Vec::new();
+
     for entity in to_mature {
-        // 80% workers, 20% soldiers
-        let new_role = if fastrand::u8(..) < config.lifecycle.worker_ratio_threshold {
+        let colony_id = world.get::<&ColonyMember>(entity).map(|m| m.colony_id as usize).unwrap_or(0);
+        let gyne = world.get::<&Gyne>(entity).is_ok();
+
+        // Males, and some of a mature colony's females, emerge with wings
+        if world.get::<&Male>(entity).is_ok() {
+            alates.push((entity, colony_id, false));
+            continue;
+        }
+        if !gyne
+            && mature.get(colony_id).copied().unwrap_or(false)
+            && fastrand::u8(..) < config.colony.alate_chance
+        {
+            alates.push((entity, colony_id, true));
+            continue;
+        }
+
+        // Caste follows how well the larva was fed, unless it was reared as a queen
+        let lifecycle = config.lifecycle_for(colony_id as u8);
+        let nutrition = world.get::<&Brood>(entity).map(|b| b.nutrition).unwrap_or(0);
+        let army_full = army_full.get(colony_id).copied().unwrap_or(true);
+
+        let new_role = if gyne {
+            AntRole::Queen
+        } else if nutrition >= lifecycle.soldier_nutrition && !army_full {
+            AntRole::Soldier
+        } else {
             AntRole::Worker
+        };
+        let size = if nutrition < lifecycle.minor_nutrition {
+            WorkerSize::Minor
+        } else if nutrition < lifecycle.major_nutrition {
+            WorkerSize::Media
         } else {
-            AntRole::Soldier
+            WorkerSize::Major
         };
 
+        let species = config.species.of_colony(colonies, colony_id as u8);
         let lifespan = match new_role {
-            AntRole::Worker => config.lifecycle.worker_lifespan,
-            AntRole::Soldier => config.lifecycle.soldier_lifespan,
-            _ => config.lifecycle.worker_lifespan,
+            AntRole::Worker => {
+                let traits = config.worker_sizes.traits(Some(&size));
+                (species.worker_lifespan(lifecycle) as f32 * traits.lifespan) as u32
+            }
+            AntRole::Soldier => species.soldier_lifespan(lifecycle),
+            AntRole::Queen => species.queen_lifespan(lifecycle),
+            _ => species.worker_lifespan(lifecycle),
         };
+        if new_role == AntRole::Worker {
+            let _ = world.insert_one(entity, size);
+        }
 
         if let Ok(mut ant) = world.get::<&mut Ant>(entity) {
             ant.role = new_role;
-            ant.state = AntState::Wandering;
+            ant.state = if gyne { AntState::Idle } else { AntState::Wandering };
         }
+        let _ = world.remove_one::<Gyne>(entity);
+        let _ = world.remove_one::<Pupa>(entity);
+        let _ = world.remove_one::<Brood>(entity);
+        let _ = world.remove_one::<CarriedBy>(entity);
         if let Ok(mut age) = world.get::<&mut Age>(entity) {
             age.ticks = 0;
             age.max_ticks = lifespan;
         }
     }
+
+    // Alates leave the brood pile and wait in the nest for the flight season
+    for (entity, colony_id, female) in alates {
+        let Ok((x, y)) = world.get::<&Position>(entity).map(|p| (p.x, p.y)) else { continue };
+        if !female {
+            if let Some(colony) = colonies.get_mut(colony_id) {
+                colony.males_reared += 1;
+            }
+        }
+        let _ = world.despawn(entity);
+        world.spawn((Position { x, y }, Alate { colony_id: colony_id as u8, female }));
+    }
+}
+
+/// Seasonal nuptial flight: every waiting alate takes off. Females that meet a
+/// male from another colony are fertilized and either rejoin a polygynous home
+/// nest or may found a new colony; all other alates are lost. Returns how many
+/// colonies were founded.
+pub fn nuptial_flight_system(
+    world: &mut World,
+    terrain: &mut Terrain,
+    colonies: &mut Vec<ColonyState>,
+    flow_field: &mut FlowField,
+    config: &SimConfig,
+) -> usize {
+    let alates: Vec<(Entity, u8, bool)> = world
+        .query::<&Alate>()
+        .iter()
+        .map(|(e, alate)| (e, alate.colony_id, alate.female))
+        .collect();
+    let male_colonies: HashSet<u8> = alates.iter().filter(|a| !a.2).map(|a| a.1).collect();
+
+    // Polygynous colonies may take their own mated daughters back in
+    let mut queens = vec![0u32; colonies.len()];
+    for (_entity, (ant, member)) in world.query::<(&Ant, &ColonyMember)>().iter() {
+        if ant.role == AntRole::Queen {
+            if let Some(count) = queens.get_mut(member.colony_id as usize) {
+                *count += 1;
+            }
+        }
+    }
+
+    let mut founded = 0;
+    let mut adopted = 0;
+    for (entity, colony_id, female) in alates {
+        let _ = world.despawn(entity);
+
+        let mated = female && male_colonies.iter().any(|&c| c != colony_id);
+        if !mated {
+            continue;
+        }
+
+        if let Some(home) = colonies.get(colony_id as usize) {
+            let room = queens[colony_id as usize] < config.colony.max_queens;
+            if home.status == ColonyStatus::Thriving
+                && room
+                && fastrand::u8(..) < config.colony.adopt_queen_chance
+            {
+                crate::systems::spawn::spawn_ant(world, home.home_x, home.home_y, colony_id, AntRole::Queen);
+                queens[colony_id as usize] += 1;
+                adopted += 1;
+                continue;
+            }
+        }
+
+        if founded >= config.colony.max_foundings_per_flight {
+            continue;
+        }
+
+        let species = colonies.get(colony_id as usize).map(|c| c.species).unwrap_or(0);
+        if let Some(colony) =
+            crate::systems::spawn::found_colony(world, terrain, colonies, species, flow_field, config)
+        {
+            // Founders take over extinct colonies' slots, or extend the list
+            match colonies.get_mut(colony.id as usize) {
+                Some(slot) => *slot = colony,
+                None => colonies.push(colony),
+            }
+            founded += 1;
+        }
+    }
+
+    // New and adopted queens need lifespans, and new nests need distance fields
+    if founded + adopted > 0 {
+        ensure_queen_ages(world, colonies, config);
+    }
+    if founded > 0 {
+        flow_field.request_rebuild();
+    }
+    founded
+}
+
+/// Competing queens attack rivals that come close to the dominant queen
+fn queen_rivalry(world: &mut World, num_colonies: usize, config: &SimConfig) {
+    let dominant = dominant_queens(world, num_colonies, config);
+    let mut executed: Vec<Entity> = Vec::new();
+
+    for (entity, (pos, ant, member)) in world.query::<(&Position, &Ant, &ColonyMember)>().iter() {
+        if ant.role != AntRole::Queen {
+            continue;
+        }
+        let Some(Some(rival)) = dominant.get(member.colony_id as usize).copied() else { continue };
+        if rival == entity {
+            continue;
+        }
+        let Ok(rival_pos) = world.get::<&Position>(rival) else { continue };
+        let distance = (pos.x - rival_pos.x).abs().max((pos.y - rival_pos.y).abs());
+        if distance <= config.colony.queen_contact_distance
+            && fastrand::u8(..) < config.colony.queen_execution_chance
+        {
+            executed.push(entity);
+        }
+    }
+
+    for entity in executed {
+        let _ = world.insert(entity, (Dead, DeathCause::Combat));
+    }
+}
+
+/// Track whether each colony still has a queen. Queenless colonies rear the
+/// best-fed larva as a successor, long-queenless workers start laying male
+/// eggs, and colonies with no way to raise a queen collapse and go extinct.
+fn colony_status_system(world: &mut World, colonies: &mut [ColonyState], tick: u64, config: &SimConfig) {
+    if config.colony.queen_relations == QueenRelations::Compete {
+        queen_rivalry(world, colonies.len(), config);
+    }
+
+    let mut queens = vec![0u32; colonies.len()];
+    let mut gynes = vec![0u32; colonies.len()];
+    let mut members = vec![0u32; colonies.len()];
+    // Best unclaimed female larva per colony: (entity, nutrition)
+    let mut candidates: Vec<Option<(Entity, u16)>> = vec![None; colonies.len()];
+    let mut female_brood = vec![0u32; colonies.len()];
+
+    for (entity, (ant, member)) in world.query::<(&Ant, &ColonyMember)>().iter() {
+        let id = member.colony_id as usize;
+        if id >= colonies.len() {
+            continue;
+        }
+        members[id] += 1;
+
+        let male = world.get::<&Male>(entity).is_ok();
+        match ant.role {
+            AntRole::Queen => queens[id] += 1,
+            AntRole::Egg if !male => female_brood[id] += 1,
+            AntRole::Larvae if !male => {
+                female_brood[id] += 1;
+                if world.get::<&Gyne>(entity).is_ok() {
+                    gynes[id] += 1;
+                } else if world.get::<&Pupa>(entity).is_err() {
+                    let nutrition = world.get::<&Brood>(entity).map(|b| b.nutrition).unwrap_or(0);
+                    if candidates[id].map(|(_, best)| nutrition > best).unwrap_or(true) {
+                        candidates[id] = Some((entity, nutrition));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut new_gynes: Vec<Entity> = Vec::new();
+    let mut layers: Vec<usize> = Vec::new();
+    let mut extinct: Vec<u8> = Vec::new();
+
+    for (id, colony) in colonies.iter_mut().enumerate() {
+        if colony.status == ColonyStatus::Extinct {
+            continue;
+        }
+
+        if members[id] == 0 {
+            colony.status = ColonyStatus::Extinct;
+            colony.extinct_at = Some(tick);
+            colony.queen_alive = false;
+            colony.nursery = None;
+            colony.larder = None;
+            colony.food_stored = 0;
+            extinct.push(colony.id);
+            continue;
+        }
+
+        colony.queen_alive = queens[id] > 0;
+        if colony.queen_alive {
+            colony.status = ColonyStatus::Thriving;
+            colony.queenless_since = None;
+
+            // Polygynous colonies with room for another queen rear one now and then
+            if queens[id] + gynes[id] < config.colony.max_queens
+                && fastrand::u8(..) < config.colony.raise_queen_chance
+                && colony_mature(world, colony, config)
+            {
+                if let Some((larva, _)) = candidates[id] {
+                    new_gynes.push(larva);
+                }
+            }
+            continue;
+        }
+        let since = *colony.queenless_since.get_or_insert(tick);
+
+        // Start rearing a successor from the best-fed larva
+        if config.colony.raise_new_queens && gynes[id] == 0 {
+            if let Some((larva, _)) = candidates[id] {
+                new_gynes.push(larva);
+                gynes[id] += 1;
+            }
+        }
+
+        // Workers give up waiting and lay unfertilized (male) eggs
+        if config.colony.worker_laying
+            && gynes[id] == 0
+            && tick - since >= config.colony.worker_laying_delay
+            && colony.food_stored >= config.lifecycle_for(colony.id).food_per_egg
+            && fastrand::u8(..) < config.colony.worker_lay_chance
+        {
+            colony.food_stored -= config.lifecycle_for(colony.id).food_per_egg;
+            layers.push(id);
+        }
+
+        let can_recover = gynes[id] > 0 || (config.colony.raise_new_queens && female_brood[id] > 0);
+        colony.status = if can_recover { ColonyStatus::Queenless } else { ColonyStatus::Collapsing };
+    }
+
+    for larva in new_gynes {
+        let _ = world.insert_one(larva, Gyne);
+    }
+
+    for colony_id in extinct {
+        release_colony(world, colony_id);
+    }
+
+    // A random worker of each laying colony drops an egg where it stands
+    for id in layers {
+        let workers: Vec<(i32, i32)> = world
+            .query::<(&Position, &Ant, &ColonyMember)>()
+            .iter()
+            .filter(|(_, (_, ant, member))| member.colony_id as usize == id && ant.role == AntRole::Worker)
+            .map(|(_, (pos, _, _))| (pos.x, pos.y))
+            .collect();
+        if workers.is_empty() {
+            continue;
+        }
+        let (x, y) = workers[fastrand::usize(..workers.len())];
+
+        world.spawn((
+            Position { x, y },
+            Ant {
+                role: AntRole::Egg,
+                state: AntState::Idle,
+            },
+            ColonyMember { colony_id: id as u8 },
+            Age {
+                ticks: 0,
+                max_ticks: config.lifecycle_for(id as u8).egg_hatch_time,
+            },
+            Male,
+        ));
+    }
+}
+
+/// Drop every reference an extinct colony leaves behind, so a founder that
+/// reuses its slot starts without the old herd or the old nest's alates
+fn release_colony(world: &mut World, colony_id: u8) {
+    for (_, aphid) in world.query::<&mut Aphid>().iter() {
+        if aphid.colony_owner == Some(colony_id) {
+            aphid.colony_owner = None;
+        }
+    }
+
+    let orphans: Vec<Entity> = world
+        .query::<&Alate>()
+        .iter()
+        .filter(|(_, alate)| alate.colony_id == colony_id)
+        .map(|(e, _)| e)
+        .collect();
+    for entity in orphans {
+        let _ = world.despawn(entity);
+    }
 }
 
 /// Age all ants and kill those past their lifespan
-fn age_and_die(world: &mut World, _tick: u64) {
+fn age_and_die(world: &mut World, _tick: u64, config: &SimConfig) {
     let mut to_die: Vec<hecs::Entity> = Vec::new();
     let mut to_age: Vec<hecs::Entity> = Vec::new();
 
-    for (entity, (ant, age)) in world.query::<(&Ant, &Age)>().iter() {
+    for (entity, (ant, member, age)) in world.query::<(&Ant, &ColonyMember, &Age)>().iter() {
         // Queens, workers, soldiers age
         if matches!(
             ant.role,
@@ -143,9 +693,14 @@ fn age_and_die(world: &mut World, _tick: u64) {
             } else {
                 to_age.push(entity);
             }
-        } else {
-            // Eggs and larvae age too
-            to_age.push(entity);
+        } else if world.get::<&Pupa>(entity).is_err() {
+            // Eggs and larvae age too, neglected brood only some of the time
+            // (pupae develop in brood_care_system, where warmth is known)
+            let neglected = world.get::<&Brood>(entity).map(|b| b.neglected).unwrap_or(false);
+            let growth_chance = config.lifecycle_for(member.colony_id).neglected_growth_chance;
+            if !neglected || fastrand::u8(..) < growth_chance {
+                to_age.push(entity);
+            }
         }
     }
 
@@ -158,55 +713,553 @@ fn age_and_die(world: &mut World, _tick: u64) {
 
     // Mark dead entities
     for entity in to_die {
-        let _ = world.insert_one(entity, Dead);
+        let _ = world.insert(entity, (Dead, DeathCause::OldAge));
     }
 }
 
-/// Consume food from colonies based on population
+/// Drain every ant's satiety; hungry ants eat a meal from colony stores if they
+/// can reach them, and ants that run empty starve
 fn consume_food(world: &mut World, colonies: &mut [ColonyState], config: &SimConfig) {
-    // Count population per colony
-    let mut food_needed: Vec<u32> = vec![0; colonies.len()];
+    // Newly hatched larvae and freshly spawned adults start with a full stomach
+    let mut unfed: Vec<(hecs::Entity, u16)> = Vec::new();
+    for (entity, (ant, member)) in world.query::<(&Ant, &ColonyMember)>().iter() {
+        // Eggs and pupae don't consume food
+        if ant.role != AntRole::Egg
+            && world.get::<&Satiety>(entity).is_err()
+            && world.get::<&Pupa>(entity).is_err()
+        {
+            unfed.push((entity, config.lifecycle_for(member.colony_id).satiety_max));
+        }
+    }
+    for (entity, value) in unfed {
+        let _ = world.insert_one(entity, Satiety { value });
+    }
 
-    for (_entity, (ant, member)) in world.query::<(&Ant, &ColonyMember)>().iter() {
-        let colony_id = member.colony_id as usize;
-        if colony_id >= colonies.len() {
+    let mut starved: Vec<hecs::Entity> = Vec::new();
+
+    for (entity, (pos, ant, member, satiety, size, replete)) in world
+        .query::<(
+            &Position,
+            &Ant,
+            &ColonyMember,
+            &mut Satiety,
+            Option<&WorkerSize>,
+            Option<&mut Replete>,
+        )>()
+        .iter()
+    {
+        let lifecycle = config.lifecycle_for(member.colony_id);
+        let drain = if ant.role == AntRole::Larvae { lifecycle.larva_satiety_drain } else { lifecycle.satiety_drain };
+        satiety.value = satiety.value.saturating_sub(drain);
+
+        if satiety.value < lifecycle.hungry_threshold {
+            // Bigger workers need bigger meals
+            let cost = if ant.role == AntRole::Worker {
+                (lifecycle.ant_food_cost as f32 * config.worker_sizes.traits(size).food_cost).round() as u32
+            } else {
+                lifecycle.ant_food_cost
+            };
+
+            // Repletes hanging in the larder live off what they hold, and
+            // draw on the stores from there once they run dry
+            let in_larder = replete.is_some()
+                && colonies.get(member.colony_id as usize).and_then(|c| c.larder).is_some_and(|(lx, ly)| {
+                    (pos.x - lx).abs().max((pos.y - ly).abs()) <= config.repletes.larder_spread
+                });
+            if let Some(replete) = replete.filter(|r| r.stored >= cost) {
+                replete.stored -= cost;
+                satiety.value = lifecycle.satiety_max;
+            } else if let Some(colony) = colonies.get_mut(member.colony_id as usize) {
+                // Queens are fed where they lie and larvae wait for a nurse;
+                // everyone else must come home (repletes are home in the larder)
+                let at_nest = match ant.role {
+                    AntRole::Queen => true,
+                    AntRole::Larvae => false,
+                    _ => {
+                        in_larder
+                            || (pos.x - colony.home_x).abs() + (pos.y - colony.home_y).abs()
+                                <= lifecycle.feed_distance
+                    }
+                };
+
+                if at_nest && colony.food_stored >= cost {
+                    colony.food_stored -= cost;
+                    satiety.value = lifecycle.satiety_max;
+                }
+            }
+        }
+
+        if satiety.value == 0 {
+            starved.push(entity);
+        }
+    }
+
+    for entity in starved {
+        let _ = world.insert(entity, (Dead, DeathCause::Starvation));
+    }
+}
+
+/// Brood care - pick nursery chambers, run nurse errands, track neglected
+/// brood and send idle workers to look after it
+#[allow(clippy::too_many_arguments)]
+pub fn brood_care_system(
+    world: &mut World,
+    terrain: &Terrain,
+    water: &WaterGrid,
+    colonies: &mut [ColonyState],
+    flow_field: &mut FlowField,
+    spatial_grid: &SpatialGrid,
+    tick: u64,
+    config: &SimConfig,
+) {
+    // A flooding nursery gets replaced right away rather than at the next check
+    let flooded = colonies.iter().any(|c| c.nursery.is_some_and(|(x, y)| water.depth(x, y) > 0));
+    if flooded || tick % config.lifecycle.nursery_check_interval as u64 == 0 {
+        choose_nurseries(terrain, water, colonies, flow_field, config);
+    }
+
+    // Freshly laid eggs start out cared for
+    let mut untracked: Vec<Entity> = Vec::new();
+    for (entity, ant) in world.query::<&Ant>().iter() {
+        if matches!(ant.role, AntRole::Egg | AntRole::Larvae) && world.get::<&Brood>(entity).is_err() {
+            untracked.push(entity);
+        }
+    }
+    for entity in untracked {
+        let _ = world.insert_one(entity, Brood { neglected: false, neglected_ticks: 0, nutrition: 0 });
+    }
+
+    run_nurse_tasks(world, water, colonies, config);
+    claustral_care(world, colonies, config);
+    track_neglect(world, water, colonies, config);
+    assign_nurses(world, water, colonies, spatial_grid, config);
+    develop_pupae(world, water, colonies, config);
+}
+
+/// Pick a dry tunnel tile below each nest as its nursery, keeping the current
+/// one for as long as it stays dry and open
+fn choose_nurseries(
+    terrain: &Terrain,
+    water: &WaterGrid,
+    colonies: &mut [ColonyState],
+    flow_field: &mut FlowField,
+    config: &SimConfig,
+) {
+    for colony in colonies.iter_mut() {
+        let (home_x, home_y, id) = (colony.home_x, colony.home_y, colony.id);
+        let lifecycle = config.lifecycle_for(id);
+        let suitable = |x: i32, y: i32| {
+            terrain.get(x, y) == Some(TerrainType::Tunnel)
+                && y - home_y >= lifecycle.nursery_min_depth
+                && water.depth(x, y) == 0
+                && flow_field.nest_distance(id, x, y).is_some()
+        };
+
+        if let Some((x, y)) = colony.nursery {
+            if suitable(x, y) {
+                continue;
+            }
+        }
+
+        // Closest suitable tile by walking distance from the entrance
+        let r = lifecycle.nursery_radius;
+        let mut best: Option<(u16, (i32, i32))> = None;
+        for y in home_y..=home_y + r {
+            for x in home_x - r..=home_x + r {
+                if !suitable(x, y) {
+                    continue;
+                }
+                let Some(d) = flow_field.nest_distance(id, x, y) else { continue };
+                if best.is_none_or(|(best_d, _)| d < best_d) {
+                    best = Some((d, (x, y)));
+                }
+            }
+        }
+
+        let site = best.map(|(_, site)| site);
+        if site != colony.nursery {
+            colony.nursery = site;
+            flow_field.set_nursery(id, site, terrain);
+        }
+    }
+}
+
+/// Brood within reach of the nursery, or of the entrance while the colony has
+/// no nursery yet
+fn in_nursery(colony: &ColonyState, x: i32, y: i32, config: &SimConfig) -> bool {
+    let (nx, ny) = colony.nursery.unwrap_or((colony.home_x, colony.home_y));
+    (x - nx).abs().max((y - ny).abs()) <= config.lifecycle_for(colony.id).nursery_spread
+}
+
+/// Advance each nurse's errand: pick up brood, carry it along, drop it in the
+/// nursery, or feed a larva once alongside it
+fn run_nurse_tasks(world: &mut World, water: &WaterGrid, colonies: &mut [ColonyState], config: &SimConfig) {
+    let nurses: Vec<(Entity, i32, i32, u8, NurseTask, u32)> = world
+        .query::<(&Position, &ColonyMember, &Nurse)>()
+        .iter()
+        .map(|(e, (pos, member, nurse))| (e, pos.x, pos.y, member.colony_id, nurse.task, nurse.ticks))
+        .collect();
+
+    let mut finished: Vec<Entity> = Vec::new();
+
+    for (nurse, x, y, colony_id, task, ticks) in nurses {
+        let brood = task.brood();
+        let lifecycle = config.lifecycle_for(colony_id);
+        let still_brood = world
+            .get::<&Ant>(brood)
+            .map(|a| matches!(a.role, AntRole::Egg | AntRole::Larvae))
+            .unwrap_or(false);
+        let brood_pos = world.get::<&Position>(brood).map(|p| (p.x, p.y)).ok();
+
+        // Give up on brood that hatched, died or took too long to reach
+        if !still_brood || ticks >= lifecycle.nurse_task_ticks {
+            finished.push(nurse);
+            continue;
+        }
+        let (Some(colony), Some((bx, by))) = (colonies.get_mut(colony_id as usize), brood_pos) else {
+            finished.push(nurse);
+            continue;
+        };
+
+        let adjacent = (x - bx).abs().max((y - by).abs()) <= 1;
+        let mut next = task;
+
+        match task {
+            NurseTask::Fetch(_) if adjacent => {
+                if world.get::<&CarriedBy>(brood).is_ok() {
+                    finished.push(nurse); // Someone else got there first
+                    continue;
+                }
+                let _ = world.insert_one(brood, CarriedBy(nurse));
+                next = NurseTask::Deliver(brood);
+            }
+            NurseTask::Deliver(_) => {
+                if in_nursery(colony, x, y, config) && water.depth(x, y) == 0 {
+                    finished.push(nurse);
+                }
+            }
+            NurseTask::Feed(_) if adjacent => {
+                feed_larva(world, brood, colony, lifecycle);
+                finished.push(nurse);
+                continue;
+            }
+            _ => {}
+        }
+
+        // Carried brood travels with its nurse
+        if next == NurseTask::Deliver(brood) {
+            if let Ok(mut pos) = world.get::<&mut Position>(brood) {
+                pos.x = x;
+                pos.y = y;
+            }
+        }
+        if let Ok(mut n) = world.get::<&mut Nurse>(nurse) {
+            n.task = next;
+            n.ticks += 1;
+        }
+    }
+
+    // Put down whatever was being carried and go back to normal work
+    for nurse in finished {
+        if let Ok(Nurse { task: NurseTask::Deliver(brood), .. }) = world.remove_one::<Nurse>(nurse) {
+            let _ = world.remove_one::<CarriedBy>(brood);
+        }
+    }
+}
+
+/// Give a larva a meal from the colony's stores, if there is food for one
+fn feed_larva(world: &mut World, larva: Entity, colony: &mut ColonyState, lifecycle: &LifecycleConfig) {
+    if colony.food_stored < lifecycle.larvae_food_cost {
+        return;
+    }
+    colony.food_stored -= lifecycle.larvae_food_cost;
+    if let Ok(mut satiety) = world.get::<&mut Satiety>(larva) {
+        satiety.value = lifecycle.satiety_max;
+    }
+    if let Ok(mut brood) = world.get::<&mut Brood>(larva) {
+        brood.nutrition = brood.nutrition.saturating_add(lifecycle.larvae_food_cost as u16);
+    }
+}
+
+/// Claustral founding: a queen with no workers feeds the hungry larvae around
+/// her herself, from the reserves she founded the colony with
+fn claustral_care(world: &mut World, colonies: &mut [ColonyState], config: &SimConfig) {
+    let mut workers = vec![0u32; colonies.len()];
+    let mut queens: Vec<Vec<(i32, i32)>> = vec![Vec::new(); colonies.len()];
+    for (_, (pos, ant, member)) in world.query::<(&Position, &Ant, &ColonyMember)>().iter() {
+        let id = member.colony_id as usize;
+        match ant.role {
+            AntRole::Worker if id < colonies.len() => workers[id] += 1,
+            AntRole::Queen if id < colonies.len() => queens[id].push((pos.x, pos.y)),
+            _ => {}
+        }
+    }
+
+    let mut hungry: Vec<(Entity, usize)> = Vec::new();
+    for (entity, (pos, ant, member, satiety)) in
+        world.query::<(&Position, &Ant, &ColonyMember, &Satiety)>().iter()
+    {
+        let id = member.colony_id as usize;
+        if ant.role != AntRole::Larvae || workers.get(id) != Some(&0) {
             continue;
         }
+        let lifecycle = config.lifecycle_for(member.colony_id);
+        let beside_queen = queens[id]
+            .iter()
+            .any(|&(qx, qy)| (pos.x - qx).abs().max((pos.y - qy).abs()) <= lifecycle.nursery_spread);
+        if satiety.value < lifecycle.hungry_threshold && beside_queen {
+            hungry.push((entity, id));
+        }
+    }
 
-        let cost = match ant.role {
-            AntRole::Larvae => config.lifecycle.larvae_food_cost,
-            AntRole::Queen | AntRole::Worker | AntRole::Soldier => config.lifecycle.ant_food_cost,
-            AntRole::Egg => 0, // Eggs don't consume food
+    for (larva, id) in hungry {
+        let lifecycle = config.lifecycle_for(id as u8);
+        feed_larva(world, larva, &mut colonies[id], lifecycle);
+    }
+}
+
+/// Brood left outside the nursery, hungry or under water is neglected: it
+/// develops slowly and dies if nobody looks after it for too long
+fn track_neglect(world: &mut World, water: &WaterGrid, colonies: &[ColonyState], config: &SimConfig) {
+    let mut dropped: Vec<Entity> = Vec::new();
+    let mut died: Vec<Entity> = Vec::new();
+
+    for (entity, (pos, ant, member, brood, carried)) in world
+        .query::<(&Position, &Ant, &ColonyMember, &mut Brood, Option<&CarriedBy>)>()
+        .iter()
+    {
+        // Carriers that died or gave up leave their load where it was
+        let carried = match carried {
+            Some(CarriedBy(carrier)) => {
+                let held = world
+                    .get::<&Nurse>(*carrier)
+                    .map(|n| n.task == NurseTask::Deliver(entity))
+                    .unwrap_or(false);
+                if !held {
+                    dropped.push(entity);
+                }
+                held
+            }
+            None => false,
         };
 
-        food_needed[colony_id] += cost;
+        let housed = colonies
+            .get(member.colony_id as usize)
+            .map(|c| in_nursery(c, pos.x, pos.y, config))
+            .unwrap_or(false);
+        let lifecycle = config.lifecycle_for(member.colony_id);
+        let wet = water.depth(pos.x, pos.y) > 0;
+        let hungry = ant.role == AntRole::Larvae
+            && world
+                .get::<&Satiety>(entity)
+                .map(|s| s.value < lifecycle.hungry_threshold)
+                .unwrap_or(false);
+
+        brood.neglected = hungry || (wet && !carried) || !(housed || carried);
+        if brood.neglected {
+            brood.neglected_ticks += 1;
+            if brood.neglected_ticks >= lifecycle.brood_neglect_limit {
+                died.push(entity);
+            }
+        } else {
+            brood.neglected_ticks = brood.neglected_ticks.saturating_sub(1);
+        }
+    }
+
+    for entity in dropped {
+        let _ = world.remove_one::<CarriedBy>(entity);
+    }
+    for entity in died {
+        let _ = world.insert(entity, (Dead, DeathCause::Neglect));
+    }
+}
+
+/// Send the nearest free worker to each piece of neglected brood: stray or
+/// flooded brood gets fetched to the nursery, hungry larvae already there get
+/// fed. Colonies with food to spare also keep feeding housed larvae until they
+/// are big enough to become soldiers.
+fn assign_nurses(
+    world: &mut World,
+    water: &WaterGrid,
+    colonies: &[ColonyState],
+    spatial_grid: &SpatialGrid,
+    config: &SimConfig,
+) {
+    let mut tended: HashSet<Entity> = world.query::<&Nurse>().iter().map(|(_, n)| n.task.brood()).collect();
+
+    let mut needy: Vec<(Entity, i32, i32, u8, bool, bool)> = Vec::new();
+    for (entity, (pos, ant, member, brood)) in
+        world.query::<(&Position, &Ant, &ColonyMember, &Brood)>().iter()
+    {
+        if tended.contains(&entity) || world.get::<&CarriedBy>(entity).is_ok() {
+            continue;
+        }
+        let Some(colony) = colonies.get(member.colony_id as usize) else { continue };
+        let housed = in_nursery(colony, pos.x, pos.y, config);
+        let wet = water.depth(pos.x, pos.y) > 0;
+        let feed = housed && !wet && ant.role == AntRole::Larvae && world.get::<&Pupa>(entity).is_err();
+        let lifecycle = config.lifecycle_for(colony.id);
+        let surplus = colony.food_stored >= lifecycle.larva_feed_reserve;
+        let growing = feed && surplus && brood.nutrition < lifecycle.soldier_nutrition;
+
+        if brood.neglected || growing {
+            needy.push((entity, pos.x, pos.y, member.colony_id, feed, wet));
+        }
+    }
+
+    let mut assignments: Vec<(Entity, NurseTask)> = Vec::new();
+    for (brood, bx, by, colony_id, feed, wet) in needy {
+        // Flooded brood is an emergency and gets the first free worker
+        let lifecycle = config.lifecycle_for(colony_id);
+        if !wet && fastrand::u8(..) >= lifecycle.nurse_assign_chance {
+            continue;
+        }
+
+        let nearest = spatial_grid
+            .query_radius(bx, by, lifecycle.nurse_radius)
+            .into_iter()
+            .filter(|&(e, _, _, c)| {
+                c == colony_id
+                    && !tended.contains(&e)
+                    && world.get::<&Nurse>(e).is_err()
+                    && world.get::<&Escaping>(e).is_err()
+                    && world.get::<&Replete>(e).is_err()
+                    && world
+                        .get::<&Ant>(e)
+                        .map(|a| {
+                            a.role == AntRole::Worker
+                                && matches!(a.state, AntState::Idle | AntState::Wandering)
+                        })
+                        .unwrap_or(false)
+            })
+            .min_by_key(|&(_, x, y, _)| (x - bx).abs().max((y - by).abs()));
+
+        if let Some((nurse, _, _, _)) = nearest {
+            // Nurses and their charges are both off the market this tick
+            tended.insert(nurse);
+            tended.insert(brood);
+            let task = if feed { NurseTask::Feed(brood) } else { NurseTask::Fetch(brood) };
+            assignments.push((nurse, task));
+        }
+    }
+
+    for (nurse, task) in assignments {
+        let _ = world.insert_one(nurse, Nurse { task, ticks: 0 });
+        if let Ok(mut ant) = world.get::<&mut Ant>(nurse) {
+            ant.state = AntState::Idle;
+        }
+    }
+}
+
+/// Pupae develop fastest in warm, shallow, dry chambers with nurses around;
+/// cool depths, damp and neglect each slow them down
+fn develop_pupae(world: &mut World, water: &WaterGrid, colonies: &[ColonyState], config: &SimConfig) {
+    for (_entity, (pos, member, age, brood, _pupa)) in
+        world.query::<(&Position, &ColonyMember, &mut Age, &Brood, &Pupa)>().iter()
+    {
+        let lifecycle = config.lifecycle_for(member.colony_id);
+        let warm = colonies
+            .get(member.colony_id as usize)
+            .map(|c| pos.y - c.home_y <= lifecycle.pupa_warm_depth)
+            .unwrap_or(false)
+            && water.depth(pos.x, pos.y) == 0;
+
+        if !warm && fastrand::u8(..) >= lifecycle.pupa_cold_growth_chance {
+            continue;
+        }
+        if brood.neglected && fastrand::u8(..) >= lifecycle.neglected_growth_chance {
+            continue;
+        }
+        age.ticks += 1;
     }
+}
 
-    // Deduct food
-    for (i, colony) in colonies.iter_mut().enumerate() {
-        if i < food_needed.len() {
-            colony.food_stored = colony.food_stored.saturating_sub(food_needed[i]);
+/// Movement for nurses: walk up to their brood, then carry it down the
+/// nursery field (or home, if the colony has no nursery yet)
+pub fn nurse_movement(
+    pos: &Position,
+    member: &ColonyMember,
+    task: NurseTask,
+    world: &World,
+    flow_field: &FlowField,
+    terrain: &Terrain,
+) -> Option<(i32, i32)> {
+    match task {
+        NurseTask::Fetch(brood) | NurseTask::Feed(brood) => {
+            let target = world.get::<&Position>(brood).ok()?;
+            if (pos.x - target.x).abs().max((pos.y - target.y).abs()) <= 1 {
+                return Some((0, 0));
+            }
+            crate::systems::food::direct_step(pos, target.x, target.y, terrain)
         }
+        NurseTask::Deliver(_) => flow_field
+            .nursery_step(member.colony_id, pos.x, pos.y)
+            .or_else(|| flow_field.nest_step(member.colony_id, pos.x, pos.y)),
     }
 }
 
 /// Add Age component to queens that don't have one
-pub fn ensure_queen_ages(world: &mut World, config: &SimConfig) {
-    let mut queens_without_age: Vec<hecs::Entity> = Vec::new();
+pub fn ensure_queen_ages(world: &mut World, colonies: &[ColonyState], config: &SimConfig) {
+    let mut queens_without_age: Vec<(hecs::Entity, u32)> = Vec::new();
 
-    for (entity, ant) in world.query::<&Ant>().iter() {
+    for (entity, (ant, member)) in world.query::<(&Ant, &ColonyMember)>().iter() {
         if ant.role == AntRole::Queen && world.get::<&Age>(entity).is_err() {
-            queens_without_age.push(entity);
+            let species = config.species.of_colony(colonies, member.colony_id);
+            queens_without_age.push((entity, species.queen_lifespan(config.lifecycle_for(member.colony_id))));
         }
     }
 
-    for entity in queens_without_age {
+    for (entity, lifespan) in queens_without_age {
         let _ = world.insert_one(
             entity,
             Age {
                 ticks: 0,
-                max_ticks: config.lifecycle.queen_lifespan,
+                max_ticks: lifespan,
             },
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::{hazard, spawn};
+
+    #[test]
+    fn founded_colony_raises_its_first_worker() {
+        fastrand::seed(7);
+        let config = SimConfig::default();
+        let mut terrain = Terrain::generate(120, 60, 7);
+        let water = WaterGrid::new(terrain.width, terrain.height, config.water.max_depth);
+        let mut world = World::new();
+        let mut colonies: Vec<ColonyState> = Vec::new();
+        let mut flow_field = FlowField::new(&terrain, &colonies);
+
+        let colony = spawn::found_colony(&mut world, &mut terrain, &colonies, 0, &mut flow_field, &config)
+            .expect("room for a colony");
+        colonies.push(colony);
+        ensure_queen_ages(&mut world, &colonies, &config);
+        flow_field.rebuild(&terrain, &colonies);
+
+        // A lone queen, no workers to nurse or share food
+        let mut grid = SpatialGrid::new(terrain.width, terrain.height, 8);
+        let mut workers = 0;
+        for tick in 1..=20_000 {
+            grid.clear();
+            for (entity, (pos, member)) in world.query::<(&Position, &ColonyMember)>().iter() {
+                grid.insert(entity, pos.x, pos.y, member.colony_id);
+            }
+            brood_care_system(&mut world, &terrain, &water, &mut colonies, &mut flow_field, &grid, tick, &config);
+            lifecycle_system(&mut world, &terrain, &mut colonies, tick, &config);
+            hazard::cleanup_dead(&mut world, &mut colonies);
+
+            workers = colonies[0].population_summary(&world).workers;
+            if workers > 0 {
+                break;
+            }
+        }
+
+        assert_eq!(workers, 1, "the founding queen should rear her first worker");
+        assert!(colonies[0].queen_alive);
+    }
+}
@@ -1,110 +1,496 @@
+use std::collections::{HashMap, HashSet};
+
 use hecs::World;
 
 use crate::colony::ColonyState;
 use crate::components::{Ant, AntRole, AntState, ColonyMember, Position};
-use crate::config::SimConfig;
-use crate::systems::pheromone::PheromoneGrid;
+use crate::config::{MovementConfig, SimConfig};
+use crate::flow_field::FlowField;
+use crate::spatial::SpatialGrid;
+use crate::systems::food::{AntMemory, ForageContext, Leaf, Replete, TandemLeader};
+use crate::systems::lifecycle::{Nurse, Satiety};
+use crate::systems::pheromone::{PheromoneGrid, PheromoneType};
+use crate::systems::water::WaterGrid;
 use crate::terrain::{Terrain, TerrainType};
 
-/// Move ants based on their state
+/// Compass directions clockwise from north, indexed by `Heading`
+const COMPASS: [(i32, i32); 8] = [
+    (0, -1), (1, -1), (1, 0), (1, 1),
+    (0, 1), (-1, 1), (-1, 0), (-1, -1),
+];
+
+/// Direction an ant is facing, as an index into `COMPASS`.
+/// Updated to the direction of travel whenever the ant moves.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Heading(pub u8);
+
+impl Heading {
+    pub fn random() -> Self {
+        Heading(fastrand::u8(..8))
+    }
+
+    pub fn from_delta(dx: i32, dy: i32) -> Option<Self> {
+        let dir = (dx.signum(), dy.signum());
+        COMPASS
+            .iter()
+            .position(|&d| d == dir)
+            .map(|i| Heading(i as u8))
+    }
+
+    pub fn delta(self) -> (i32, i32) {
+        COMPASS[self.0 as usize % COMPASS.len()]
+    }
+
+    /// Rotate by 45-degree steps (positive = clockwise)
+    pub fn turned(self, steps: i32) -> Self {
+        Heading((self.0 as i32 + steps).rem_euclid(8) as u8)
+    }
+
+    /// Ahead-left, ahead and ahead-right
+    pub fn cone(self) -> [(i32, i32); 3] {
+        [self.turned(-1).delta(), self.delta(), self.turned(1).delta()]
+    }
+}
+
+/// Tracks whether a goal-directed ant is actually getting anywhere
+pub struct Progress {
+    pub anchor_x: i32,
+    pub anchor_y: i32,
+    pub stalled_ticks: u16,
+}
+
+/// Ant breaking out of a stuck spot with a few ticks of random walking
+pub struct Escaping {
+    pub ticks: u8,
+}
+
+/// Fraction of a tile an ant has covered toward its next step
+pub struct Stride {
+    pub progress: f32,
+}
+
+/// Traffic statistics from the last movement pass
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Congestion {
+    /// Moves that had to wait because the target tile was full
+    pub blocked_moves: usize,
+    /// Tiles holding as many ants as they can fit
+    pub full_tiles: usize,
+}
+
+/// A step an ant has earned this tick, waiting to be checked against tile capacity
+struct PendingMove {
+    entity: hecs::Entity,
+    from: (i32, i32),
+    to: (i32, i32),
+    stride: f32,
+    right_of_way: bool,
+}
+
+/// Move ants based on their state.
+/// Returns traffic statistics for the debug panel.
+#[allow(clippy::too_many_arguments)]
 pub fn movement_system(
     world: &mut World,
     terrain: &Terrain,
     pheromones: &PheromoneGrid,
+    water: &WaterGrid,
+    flow_field: &FlowField,
+    food_grid: &SpatialGrid,
     colonies: &[ColonyState],
     config: &SimConfig,
-) {
+) -> Congestion {
     // Collect moves to apply (can't mutate while iterating)
-    let mut moves: Vec<(hecs::Entity, i32, i32)> = Vec::new();
+    let mut moves: Vec<PendingMove> = Vec::new();
+    let mut strides: Vec<(hecs::Entity, f32)> = Vec::new();
+    let forage = ForageContext { terrain, pheromones, flow_field, food_grid, colonies, config };
+
+    for (entity, (pos, ant, member, heading, memory)) in world
+        .query::<(&Position, &Ant, &ColonyMember, Option<&Heading>, Option<&AntMemory>)>()
+        .iter()
+    {
+        let heading = heading.copied();
+        let movement = config.movement_for(member.colony_id);
 
-    for (entity, (pos, ant, member)) in world.query::<(&Position, &Ant, &ColonyMember)>().iter() {
         // Skip immobile entities
         if matches!(ant.role, AntRole::Egg | AntRole::Larvae) {
             continue;
         }
 
         // Queens move rarely
-        if ant.role == AntRole::Queen && fastrand::u8(..) > config.movement.queen_move_threshold {
+        if ant.role == AntRole::Queen && fastrand::u8(..) > movement.queen_move_threshold {
             continue;
         }
 
+        // Stuck ants walk randomly for a few ticks before resuming their goal
+        let escaping = world.get::<&Escaping>(entity).is_ok();
+        let replete = world.get::<&Replete>(entity).is_ok();
+
+        // Hungry ants drop whatever they are doing to eat at home, unless
+        // running from danger
+        let homing = if ant.state == AntState::Fleeing {
+            None
+        } else {
+            hunger_movement(entity, pos, ant, member, world, flow_field, config)
+        };
+
+        // Nurses on an errand ignore their usual state unless running from danger
+        let errand = world
+            .get::<&Nurse>(entity)
+            .ok()
+            .map(|n| n.task)
+            .filter(|_| ant.state != AntState::Fleeing);
+
         // Determine movement based on state
         let (dx, dy) = match ant.state {
-            AntState::Wandering => random_movement(),
+            _ if escaping => random_movement(pos, heading, terrain, movement),
+            _ if replete => {
+                // Repletes make their way down to the larder and hang there
+                let has_larder = colonies.get(member.colony_id as usize).is_some_and(|c| c.larder.is_some());
+                if has_larder {
+                    flow_field.larder_step(member.colony_id, pos.x, pos.y).unwrap_or((0, 0))
+                } else {
+                    flow_field.nest_step(member.colony_id, pos.x, pos.y).unwrap_or((0, 0))
+                }
+            }
+            _ if homing.is_some() => homing.unwrap_or_default(),
+            _ if errand.is_some() => {
+                match errand.and_then(|task| {
+                    crate::systems::lifecycle::nurse_movement(pos, member, task, world, flow_field, terrain)
+                }) {
+                    Some(dir) => dir,
+                    None => random_movement(pos, heading, terrain, movement),
+                }
+            }
+            AntState::Wandering => {
+                // Answer recruit calls, then pick up food trails ahead,
+                // otherwise keep walking
+                match recruit_movement(pos, ant, member, pheromones, terrain, config)
+                    .or_else(|| {
+                        crate::systems::food::foraging_movement(pos, ant, member, heading, memory, &forage)
+                    }) {
+                    Some(dir) => dir,
+                    None => random_movement(pos, heading, terrain, movement),
+                }
+            }
             AntState::Digging => dig_movement(pos, terrain),
-            AntState::Returning => climb_movement(pos, terrain),
+            AntState::Returning => {
+                // Follow the surface distance field, with the greedy climb as fallback
+                match flow_field.surface_step(pos.x, pos.y) {
+                    Some(dir) if fastrand::u8(..) < movement.flow_follow_chance => dir,
+                    _ => climb_movement(pos, terrain),
+                }
+            }
             AntState::Idle => {
-                if fastrand::u8(..) < config.movement.idle_move_threshold {
-                    random_movement()
+                if let Some(dir) = recruit_movement(pos, ant, member, pheromones, terrain, config) {
+                    dir
+                } else if fastrand::u8(..) < movement.idle_move_threshold {
+                    random_movement(pos, heading, terrain, movement)
                 } else {
                     (0, 0)
                 }
             }
+            AntState::Carrying if world.get::<&Leaf>(entity).is_ok() => {
+                // Leaf carriers take their load down to the garden in the nursery
+                match flow_field
+                    .nursery_step(member.colony_id, pos.x, pos.y)
+                    .or_else(|| flow_field.nest_step(member.colony_id, pos.x, pos.y))
+                {
+                    Some(dir) => dir,
+                    None => random_movement(pos, heading, terrain, movement),
+                }
+            }
             AntState::Carrying => {
-                match crate::systems::food::foraging_movement(
-                    pos, ant, member, terrain, pheromones, colonies, config,
-                ) {
+                match crate::systems::food::foraging_movement(pos, ant, member, heading, memory, &forage) {
                     Some(dir) => dir,
-                    None => random_movement(),
+                    None => random_movement(pos, heading, terrain, movement),
                 }
             }
             AntState::Fighting => {
                 match crate::systems::combat::fighting_movement(pos, member, pheromones) {
                     Some(dir) => dir,
-                    None => random_movement(),
+                    None => random_movement(pos, heading, terrain, movement),
                 }
             }
             AntState::Fleeing => {
-                match crate::systems::combat::fleeing_movement(pos, pheromones, config) {
+                match crate::systems::combat::fleeing_movement(pos, pheromones) {
                     Some(dir) => dir,
-                    None => random_movement(),
+                    None => random_movement(pos, heading, terrain, movement),
                 }
             }
             AntState::Following => {
-                match crate::systems::food::foraging_movement(
-                    pos, ant, member, terrain, pheromones, colonies, config,
-                ) {
+                // Tandem runners track their partner; anyone else follows trails
+                match crate::systems::food::tandem_movement(entity, pos, world, terrain, config)
+                    .or_else(|| {
+                        crate::systems::food::foraging_movement(pos, ant, member, heading, memory, &forage)
+                    }) {
                     Some(dir) => dir,
-                    None => random_movement(),
+                    None => random_movement(pos, heading, terrain, movement),
                 }
             }
         };
 
+        // Steer around deep water unless running for it
+        let fleeing = ant.state == AntState::Fleeing;
+        let (dx, dy) = if fleeing { (dx, dy) } else { water_detour(pos, dx, dy, terrain, water) };
+
         if dx != 0 || dy != 0 {
             let new_x = pos.x + dx;
             let new_y = pos.y + dy;
 
+            // Wading is slow: the tile being stepped into sets the penalty.
+            // Panicked ants thrash on even through water too deep to wade.
+            let there = water.get(new_x, new_y);
+            let mut penalty = there.movement_penalty();
+            if fleeing {
+                penalty = penalty.max(movement.flee_swim_speed);
+            }
+
             // Check if new position is valid
-            if terrain.is_passable(new_x, new_y) {
-                moves.push((entity, new_x, new_y));
+            if terrain.is_passable(new_x, new_y) && (there.is_passable() || fleeing) {
+                // Accumulate sub-tile progress; step once a whole tile is covered
+                let speed = ant_speed(pos, ant, terrain, penalty, movement);
+                let stride = world.get::<&Stride>(entity).map(|s| s.progress).unwrap_or(0.0) + speed;
+                if stride >= 1.0 {
+                    moves.push(PendingMove {
+                        entity,
+                        from: (pos.x, pos.y),
+                        to: (new_x, new_y),
+                        stride,
+                        right_of_way: matches!(
+                            ant.state,
+                            AntState::Carrying | AntState::Fighting | AntState::Fleeing
+                        ),
+                    });
+                } else {
+                    strides.push((entity, stride));
+                }
             }
         }
     }
 
-    // Apply moves
-    for (entity, new_x, new_y) in moves {
-        if let Ok(mut pos) = world.get::<&mut Position>(entity) {
-            pos.x = new_x;
-            pos.y = new_y;
+    // Count who is standing where before anyone moves; brood is piled up and
+    // carried around, so it doesn't take up room
+    let mut occupancy: HashMap<(i32, i32), usize> = HashMap::new();
+    for (_entity, (pos, ant)) in world.query::<(&Position, &Ant)>().iter() {
+        if matches!(ant.role, AntRole::Egg | AntRole::Larvae) {
+            continue;
+        }
+        *occupancy.entry((pos.x, pos.y)).or_insert(0) += 1;
+    }
+
+    // Ants heading in opposite directions through the same pair of tiles can squeeze past
+    let wanted: HashSet<((i32, i32), (i32, i32))> = moves.iter().map(|m| (m.from, m.to)).collect();
+
+    // Loaded, fighting and fleeing ants go first; everyone else yields to them
+    moves.sort_by_key(|m| !m.right_of_way);
+
+    let mut congestion = Congestion::default();
+    for m in moves {
+        let capacity = tile_capacity(terrain, m.to.0, m.to.1, config);
+        let count = occupancy.get(&m.to).copied().unwrap_or(0);
+        let passing = wanted.contains(&(m.to, m.from));
+
+        if count >= capacity as usize && !passing {
+            // Wait in place, ready to step as soon as the tile frees up
+            congestion.blocked_moves += 1;
+            strides.push((m.entity, m.stride.min(1.0)));
+            continue;
+        }
+
+        if let Some(c) = occupancy.get_mut(&m.from) {
+            *c = c.saturating_sub(1);
         }
+        *occupancy.entry(m.to).or_insert(0) += 1;
+        strides.push((m.entity, m.stride - 1.0));
+
+        // Apply the move and face the direction of travel
+        let mut heading = None;
+        if let Ok(mut pos) = world.get::<&mut Position>(m.entity) {
+            heading = Heading::from_delta(m.to.0 - pos.x, m.to.1 - pos.y);
+            pos.x = m.to.0;
+            pos.y = m.to.1;
+        }
+        if let Some(heading) = heading {
+            let _ = world.insert_one(m.entity, heading);
+        }
+    }
+
+    for (entity, progress) in strides {
+        let _ = world.insert_one(entity, Stride { progress });
     }
+
+    congestion.full_tiles = occupancy
+        .iter()
+        .filter(|(&(x, y), &count)| count > 0 && count >= tile_capacity(terrain, x, y, config) as usize)
+        .count();
+
+    congestion
 }
 
-/// Generate random movement direction
-fn random_movement() -> (i32, i32) {
-    // Bias slightly downward for digging behavior later
-    let directions = [
-        (0, -1), // up
-        (0, 1),  // down
-        (0, 1),  // down (extra weight)
-        (-1, 0), // left
-        (1, 0),  // right
-        (-1, 1), // down-left
-        (1, 1),  // down-right
-        (0, 0),  // stay (sometimes)
-    ];
+/// How many ants fit on a tile: tunnels are narrower than open ground.
+/// Tiles are shared by every colony, so this uses the shared movement section.
+fn tile_capacity(terrain: &Terrain, x: i32, y: i32, config: &SimConfig) -> u8 {
+    match terrain.get(x, y) {
+        Some(TerrainType::Tunnel) => config.movement.tunnel_capacity,
+        _ => config.movement.open_capacity,
+    }
+}
+
+/// Tiles per tick for an ant by caste, load, terrain and the water penalty of
+/// the tile it is stepping into (at most 1.0)
+fn ant_speed(pos: &Position, ant: &Ant, terrain: &Terrain, wading: f32, movement: &MovementConfig) -> f32 {
+    let mut speed = match ant.role {
+        AntRole::Soldier => movement.soldier_speed,
+        AntRole::Queen => movement.queen_speed,
+        _ => movement.worker_speed,
+    };
+
+    if ant.state == AntState::Carrying {
+        speed *= movement.carrying_speed_multiplier;
+    }
+    if terrain.get(pos.x, pos.y) == Some(TerrainType::Tunnel) {
+        speed *= movement.tunnel_speed_multiplier;
+    }
+    // Water slows ants down, but never to a standstill
+    if wading < 1.0 {
+        speed *= (wading * movement.water_speed_multiplier).clamp(movement.min_water_speed, 1.0);
+    }
+
+    speed.min(1.0)
+}
+
+/// Correlated random walk: mostly keep the current heading, sometimes turn.
+/// Walls deflect the ant to the nearest open direction instead of stopping it.
+fn random_movement(
+    pos: &Position,
+    heading: Option<Heading>,
+    terrain: &Terrain,
+    movement: &MovementConfig,
+) -> (i32, i32) {
+    // Ants without a heading yet pick one at random
+    let Some(heading) = heading else {
+        return Heading::random().delta();
+    };
+
+    // Pause occasionally
+    if fastrand::u8(..) < movement.wander_pause_chance {
+        return (0, 0);
+    }
+
+    let side = if fastrand::bool() { 1 } else { -1 };
+    let straight = movement.wander_straight_chance;
+    let roll = fastrand::u8(..);
+    let turn = if roll < straight {
+        0
+    } else if roll < straight.saturating_add(movement.wander_turn_chance) {
+        side
+    } else {
+        2 * side
+    };
+    let preferred = heading.turned(turn);
+
+    // Try the preferred direction first, then fan out to either side
+    for offset in [0, side, -side, 2 * side, -2 * side, 3 * side, -3 * side, 4] {
+        let (dx, dy) = preferred.turned(offset).delta();
+        if terrain.is_passable(pos.x + dx, pos.y + dy) {
+            return (dx, dy);
+        }
+    }
+
+    (0, 0)
+}
+
+/// If a step would lead into dangerous water, swing to the nearest direction
+/// that stays out of it; stay put if every way is flooded
+fn water_detour(
+    pos: &Position,
+    dx: i32,
+    dy: i32,
+    terrain: &Terrain,
+    water: &WaterGrid,
+) -> (i32, i32) {
+    let Some(heading) = Heading::from_delta(dx, dy) else {
+        return (dx, dy);
+    };
+    if !water.get(pos.x + dx, pos.y + dy).is_dangerous() {
+        return (dx, dy);
+    }
+
+    let side = if fastrand::bool() { 1 } else { -1 };
+    for offset in [side, -side, 2 * side, -2 * side] {
+        let (ndx, ndy) = heading.turned(offset).delta();
+        let (nx, ny) = (pos.x + ndx, pos.y + ndy);
+        if terrain.is_passable(nx, ny) && !water.get(nx, ny).is_dangerous() {
+            return (ndx, ndy);
+        }
+    }
+
+    // Already surrounded by deep water: shallower is still better than deeper
+    if water.get(pos.x + dx, pos.y + dy).depth <= water.get(pos.x, pos.y).depth {
+        (dx, dy)
+    } else {
+        (0, 0)
+    }
+}
+
+/// Hungry workers and soldiers follow the nest distance field home to eat
+fn hunger_movement(
+    entity: hecs::Entity,
+    pos: &Position,
+    ant: &Ant,
+    member: &ColonyMember,
+    world: &World,
+    flow_field: &FlowField,
+    config: &SimConfig,
+) -> Option<(i32, i32)> {
+    if !matches!(ant.role, AntRole::Worker | AntRole::Soldier) {
+        return None;
+    }
+
+    let hungry = world
+        .get::<&Satiety>(entity)
+        .map(|s| s.value < config.lifecycle_for(member.colony_id).hungry_threshold)
+        .unwrap_or(false);
+    if !hungry {
+        return None;
+    }
 
-    directions[fastrand::usize(..directions.len())]
+    flow_field.nest_step(member.colony_id, pos.x, pos.y)
+}
+
+/// Idle and wandering workers and soldiers are drawn up recruit gradients,
+/// more often the stronger the signal
+fn recruit_movement(
+    pos: &Position,
+    ant: &Ant,
+    member: &ColonyMember,
+    pheromones: &PheromoneGrid,
+    terrain: &Terrain,
+    config: &SimConfig,
+) -> Option<(i32, i32)> {
+    if !matches!(ant.role, AntRole::Worker | AntRole::Soldier) {
+        return None;
+    }
+
+    let pheromone = config.pheromone_for(member.colony_id);
+    let strength = pheromones.get(pos.x, pos.y, member.colony_id, PheromoneType::Recruit);
+    if strength < pheromone.recruit_response_threshold
+        || fastrand::f32() >= strength * pheromone.recruit_response_gain
+    {
+        return None;
+    }
+
+    // Recruit signal is sensed all around, not just ahead
+    crate::systems::pheromone::follow_pheromone(
+        pheromones,
+        pos.x,
+        pos.y,
+        member.colony_id,
+        PheromoneType::Recruit,
+        None,
+        terrain,
+    )
 }
 
 /// Movement for digging ants - prefer moving into newly dug spaces
@@ -171,3 +557,82 @@ fn climb_movement(pos: &Position, terrain: &Terrain) -> (i32, i32) {
     // Stuck, stay in place
     (0, 0)
 }
+
+/// Detect goal-directed ants that have stayed within one tile of the same spot
+/// for too long and kick them loose: reset their state and send them on a
+/// short random escape. Tandem leaders waiting for followers aren't stuck.
+/// Returns how many ants are currently escaping.
+pub fn stuck_system(world: &mut World, config: &SimConfig) -> usize {
+    let mut untracked: Vec<(hecs::Entity, i32, i32)> = Vec::new();
+    let mut stuck: Vec<(hecs::Entity, AntState, u8)> = Vec::new(); // entity, state, escape ticks
+
+    for (entity, (pos, ant, member, progress)) in
+        world.query::<(&Position, &Ant, &ColonyMember, Option<&mut Progress>)>().iter()
+    {
+        let movement = config.movement_for(member.colony_id);
+        let waiting = world.get::<&TandemLeader>(entity).is_ok();
+        let limit = match ant.state {
+            _ if waiting => 0,
+            AntState::Carrying | AntState::Returning | AntState::Following => {
+                movement.stuck_ticks
+            }
+            AntState::Digging => movement.dig_stuck_ticks,
+            _ => 0,
+        };
+
+        let Some(progress) = progress else {
+            if limit > 0 {
+                untracked.push((entity, pos.x, pos.y));
+            }
+            continue;
+        };
+
+        let moved = (pos.x - progress.anchor_x).abs().max((pos.y - progress.anchor_y).abs()) >= 2;
+        if limit == 0 || moved {
+            progress.anchor_x = pos.x;
+            progress.anchor_y = pos.y;
+            progress.stalled_ticks = 0;
+            continue;
+        }
+
+        progress.stalled_ticks += 1;
+        if progress.stalled_ticks >= limit {
+            progress.stalled_ticks = 0;
+            stuck.push((entity, ant.state, movement.escape_ticks));
+        }
+    }
+
+    for (entity, x, y) in untracked {
+        let _ = world.insert_one(entity, Progress { anchor_x: x, anchor_y: y, stalled_ticks: 0 });
+    }
+
+    for (entity, state, escape_ticks) in stuck {
+        let recovered = match state {
+            AntState::Digging => AntState::Returning,
+            AntState::Returning | AntState::Following => AntState::Wandering,
+            other => other, // Carrying ants keep their load and just escape
+        };
+        if let Ok(mut ant) = world.get::<&mut Ant>(entity) {
+            ant.state = recovered;
+        }
+        let _ = world.insert_one(entity, Heading::random());
+        let _ = world.insert_one(entity, Escaping { ticks: escape_ticks });
+    }
+
+    // Count down running escapes
+    let mut finished: Vec<hecs::Entity> = Vec::new();
+    let mut escaping = 0;
+    for (entity, escape) in world.query::<&mut Escaping>().iter() {
+        escape.ticks = escape.ticks.saturating_sub(1);
+        if escape.ticks == 0 {
+            finished.push(entity);
+        } else {
+            escaping += 1;
+        }
+    }
+    for entity in finished {
+        let _ = world.remove_one::<Escaping>(entity);
+    }
+
+    escaping
+}
@@ -2,9 +2,11 @@
 
 use hecs::World;
 
-use crate::colony::ColonyState;
+use crate::colony::{ColonyState, ColonyStatus};
 use crate::components::{Ant, AntState, ColonyMember, Position};
 use crate::config::{PheromoneConfig, SimConfig};
+use crate::systems::food::{Recruiter, TandemLeader};
+use crate::systems::movement::Heading;
 use crate::terrain::Terrain;
 
 /// Pheromone types
@@ -13,59 +15,98 @@ pub enum PheromoneType {
     Food,   // Found food, follow me
     Home,   // Path back to nest
     Danger, // Enemy/hazard here
+    Recruit, // Come here now -- rich food or a fight in progress
 }
 
+/// Number of pheromone channels stored per colony per tile
+const NUM_TYPES: usize = 4;
+
+const ALL_TYPES: [PheromoneType; NUM_TYPES] = [
+    PheromoneType::Food,
+    PheromoneType::Home,
+    PheromoneType::Danger,
+    PheromoneType::Recruit,
+];
+
 /// Pheromone grid stored in terrain
 pub struct PheromoneGrid {
     pub width: usize,
     pub height: usize,
-    /// Per-tile pheromone levels: [food, home, danger] per colony
-    /// Layout: (y * width + x) * MAX_COLONIES * 3 + colony_id * 3 + type
-    data: Vec<f32>,
-    buffer: Vec<f32>,  // Diffusion scratch buffer (permanent, not per-tick allocated)
-    pub max_colonies: usize,
+    /// One plane per colony slot; extinct colonies' planes are emptied to free
+    /// memory. Plane layout: (y * width + x) * NUM_TYPES + type
+    planes: Vec<Vec<f32>>,
+    buffer: Vec<f32>,  // Diffusion scratch plane (permanent, not per-tick allocated)
     /// Config values stored on grid to avoid cascading signature changes
     max_strength: f32,
     gradient_threshold: f32,
 }
 
 impl PheromoneGrid {
-    pub fn new(width: usize, height: usize, max_colonies: usize, config: &PheromoneConfig) -> Self {
-        let size = width * height * max_colonies * 3;
+    pub fn new(width: usize, height: usize, num_colonies: usize, config: &PheromoneConfig) -> Self {
+        let plane_size = width * height * NUM_TYPES;
         Self {
             width,
             height,
-            data: vec![0.0; size],
-            buffer: vec![0.0; size],
-            max_colonies,
+            planes: vec![vec![0.0; plane_size]; num_colonies],
+            buffer: vec![0.0; plane_size],
             max_strength: config.max_strength,
             gradient_threshold: config.gradient_threshold,
         }
     }
 
-    fn index(&self, x: i32, y: i32, colony: u8, ptype: PheromoneType) -> Option<usize> {
-        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
-            return None;
+    /// Number of colony slots, including emptied ones
+    pub fn colony_count(&self) -> usize {
+        self.planes.len()
+    }
+
+    /// Match the planes to the colony list: new colonies get a fresh plane,
+    /// extinct ones have theirs dropped
+    pub fn sync_colonies(&mut self, colonies: &[ColonyState]) {
+        let plane_size = self.width * self.height * NUM_TYPES;
+        self.planes.resize_with(colonies.len(), Vec::new);
+        for (plane, colony) in self.planes.iter_mut().zip(colonies) {
+            if colony.status == ColonyStatus::Extinct {
+                *plane = Vec::new();
+            } else if plane.is_empty() {
+                *plane = vec![0.0; plane_size];
+            }
         }
-        let base = (y as usize * self.width + x as usize) * self.max_colonies * 3;
-        let colony_offset = (colony as usize).min(self.max_colonies - 1) * 3;
-        let type_offset = match ptype {
-            PheromoneType::Food => 0,
-            PheromoneType::Home => 1,
-            PheromoneType::Danger => 2,
-        };
-        Some(base + colony_offset + type_offset)
+    }
+
+    fn index(&self, x: i32, y: i32, ptype: PheromoneType) -> Option<usize> {
+        tile_index(self.width, self.height, x, y).map(|i| i + type_offset(ptype))
     }
 
     pub fn get(&self, x: i32, y: i32, colony: u8, ptype: PheromoneType) -> f32 {
-        self.index(x, y, colony, ptype)
-            .map(|i| self.data[i])
+        let Some(i) = self.index(x, y, ptype) else { return 0.0 };
+        self.planes
+            .get(colony as usize)
+            .and_then(|plane| plane.get(i))
+            .copied()
             .unwrap_or(0.0)
     }
 
+    /// Strongest level of a pheromone type laid by any colony
+    pub fn max_any(&self, x: i32, y: i32, ptype: PheromoneType) -> f32 {
+        let Some(i) = self.index(x, y, ptype) else { return 0.0 };
+        self.planes.iter().filter_map(|plane| plane.get(i)).fold(0.0, |a, &b| a.max(b))
+    }
+
+    /// Combined level of a pheromone type laid by all colonies
+    pub fn sum_any(&self, x: i32, y: i32, ptype: PheromoneType) -> f32 {
+        let Some(i) = self.index(x, y, ptype) else { return 0.0 };
+        self.planes.iter().filter_map(|plane| plane.get(i)).sum()
+    }
+
+    fn cell_mut(&mut self, x: i32, y: i32, colony: u8, ptype: PheromoneType) -> Option<&mut f32> {
+        let i = self.index(x, y, ptype)?;
+        self.planes.get_mut(colony as usize)?.get_mut(i)
+    }
+
     pub fn deposit(&mut self, x: i32, y: i32, colony: u8, ptype: PheromoneType, amount: f32) {
-        if let Some(i) = self.index(x, y, colony, ptype) {
-            self.data[i] = (self.data[i] + amount).min(self.max_strength);
+        let max_strength = self.max_strength;
+        if let Some(cell) = self.cell_mut(x, y, colony, ptype) {
+            *cell = (*cell + amount).min(max_strength);
         }
     }
 
@@ -75,36 +116,40 @@ impl PheromoneGrid {
         &mut self, x: i32, y: i32, colony: u8,
         ptype: PheromoneType, base_amount: f32,
     ) {
-        if let Some(i) = self.index(x, y, colony, ptype) {
-            let current = self.data[i];
-            let effective = base_amount * (1.0 - current / self.max_strength);
-            self.data[i] = (current + effective).min(self.max_strength);
+        let max_strength = self.max_strength;
+        if let Some(cell) = self.cell_mut(x, y, colony, ptype) {
+            let current = *cell;
+            let effective = base_amount * (1.0 - current / max_strength);
+            *cell = (current + effective).min(max_strength);
         }
     }
 
-    pub fn decay_all(&mut self, config: &PheromoneConfig) {
-        // Data layout: strides of 3 per colony = [food, home, danger]
-        // Process in strides of 3 to apply per-type rates
-        for chunk in self.data.chunks_exact_mut(3) {
-            // Food (index 0)
-            chunk[0] *= 1.0 - config.decay_food;
-            if chunk[0] < config.snap_to_zero { chunk[0] = 0.0; }
-            // Home (index 1)
-            chunk[1] *= 1.0 - config.decay_home;
-            if chunk[1] < config.snap_to_zero { chunk[1] = 0.0; }
-            // Danger (index 2)
-            chunk[2] *= 1.0 - config.decay_danger;
-            if chunk[2] < config.snap_to_zero { chunk[2] = 0.0; }
+    /// Decay every plane at its colony's rates
+    pub fn decay_all(&mut self, config: &SimConfig) {
+        // Plane layout: strides of NUM_TYPES per tile = [food, home, danger, recruit]
+        // Process in strides to apply per-type rates
+        for (colony, plane) in self.planes.iter_mut().enumerate() {
+            let config = config.pheromone_for(colony as u8);
+            for chunk in plane.chunks_exact_mut(NUM_TYPES) {
+                // Food (index 0)
+                chunk[0] *= 1.0 - config.decay_food;
+                if chunk[0] < config.snap_to_zero { chunk[0] = 0.0; }
+                // Home (index 1)
+                chunk[1] *= 1.0 - config.decay_home;
+                if chunk[1] < config.snap_to_zero { chunk[1] = 0.0; }
+                // Danger (index 2)
+                chunk[2] *= 1.0 - config.decay_danger;
+                if chunk[2] < config.snap_to_zero { chunk[2] = 0.0; }
+                // Recruit (index 3)
+                chunk[3] *= 1.0 - config.decay_recruit;
+                if chunk[3] < config.snap_to_zero { chunk[3] = 0.0; }
+            }
         }
     }
 
-    /// Spread pheromone to 8 neighbors using double-buffer swap
-    pub fn diffuse(&mut self, config: &PheromoneConfig) {
-        // Zero the buffer
-        for v in self.buffer.iter_mut() {
-            *v = 0.0;
-        }
-
+    /// Spread pheromone to 8 neighbors using double-buffer swap, at each
+    /// colony's own diffusion rates
+    pub fn diffuse(&mut self, config: &SimConfig) {
         let cardinal_weight: f32 = 1.0;
         let diagonal_weight: f32 = 0.707; // ~1/sqrt(2)
         let total_weight: f32 = 4.0 * cardinal_weight + 4.0 * diagonal_weight;
@@ -114,36 +159,54 @@ impl PheromoneGrid {
             (-1, -1), (1, -1), (-1, 1), (1, 1),    // Diagonal
         ];
 
-        for y in 0..self.height as i32 {
-            for x in 0..self.width as i32 {
-                for colony in 0..self.max_colonies as u8 {
-                    for ptype in [PheromoneType::Food, PheromoneType::Home, PheromoneType::Danger] {
-                        if let Some(i) = self.index(x, y, colony, ptype) {
-                            let val = self.data[i];
-                            if val < config.snap_to_zero { continue; }
-
-                            let spread = val * config.diffusion_rate;
-                            self.buffer[i] += val - spread; // Cell keeps most of its value
-
-                            // Spread to neighbors
-                            for (dx, dy) in &directions {
-                                if let Some(ni) = self.index(x + dx, y + dy, colony, ptype) {
-                                    let weight = if dx.abs() + dy.abs() == 1 {
-                                        cardinal_weight
-                                    } else {
-                                        diagonal_weight
-                                    };
-                                    self.buffer[ni] += spread * weight / total_weight;
-                                }
+        let (width, height) = (self.width, self.height);
+        let buffer = &mut self.buffer;
+
+        for (colony, plane) in self.planes.iter_mut().enumerate() {
+            if plane.is_empty() {
+                continue;
+            }
+            let config = config.pheromone_for(colony as u8);
+
+            // Zero the buffer
+            for v in buffer.iter_mut() {
+                *v = 0.0;
+            }
+
+            for y in 0..height as i32 {
+                for x in 0..width as i32 {
+                    let Some(tile) = tile_index(width, height, x, y) else { continue };
+                    for ptype in ALL_TYPES {
+                        let i = tile + type_offset(ptype);
+                        let val = plane[i];
+                        if val < config.snap_to_zero { continue; }
+
+                        // Recruit signal is volatile and spreads faster than trails
+                        let rate = match ptype {
+                            PheromoneType::Recruit => config.diffusion_rate_recruit,
+                            _ => config.diffusion_rate,
+                        };
+                        let spread = val * rate;
+                        buffer[i] += val - spread; // Cell keeps most of its value
+
+                        // Spread to neighbors
+                        for (dx, dy) in &directions {
+                            if let Some(nt) = tile_index(width, height, x + dx, y + dy) {
+                                let weight = if dx.abs() + dy.abs() == 1 {
+                                    cardinal_weight
+                                } else {
+                                    diagonal_weight
+                                };
+                                buffer[nt + type_offset(ptype)] += spread * weight / total_weight;
                             }
                         }
                     }
                 }
             }
-        }
 
-        // Swap buffers (O(1) pointer swap, no allocation)
-        std::mem::swap(&mut self.data, &mut self.buffer);
+            // Swap buffers (O(1) pointer swap, no allocation)
+            std::mem::swap(plane, buffer);
+        }
     }
 
     /// Get strongest pheromone direction for a colony
@@ -189,10 +252,19 @@ impl PheromoneGrid {
             (-1, -1), (1, -1), (-1, 1), (1, 1),
         ];
 
+        self.get_gradient_weighted_in(x, y, colony, ptype, &directions)
+    }
+
+    /// Weighted random gradient selection restricted to the given directions
+    /// (e.g. an ant's forward sensing cone)
+    pub fn get_gradient_weighted_in(
+        &self, x: i32, y: i32, colony: u8, ptype: PheromoneType,
+        directions: &[(i32, i32)],
+    ) -> Option<(i32, i32)> {
         // Collect neighbors with non-negligible pheromone
         let mut candidates: Vec<((i32, i32), f32)> = Vec::new();
 
-        for (dx, dy) in directions {
+        for &(dx, dy) in directions {
             let strength = self.get(x + dx, y + dy, colony, ptype);
             if strength > self.gradient_threshold {
                 candidates.push(((dx, dy), strength));
@@ -220,24 +292,42 @@ impl PheromoneGrid {
     }
 }
 
+/// Offset of a tile's first channel within a colony plane
+fn tile_index(width: usize, height: usize, x: i32, y: i32) -> Option<usize> {
+    if x < 0 || y < 0 || x as usize >= width || y as usize >= height {
+        return None;
+    }
+    Some((y as usize * width + x as usize) * NUM_TYPES)
+}
+
+fn type_offset(ptype: PheromoneType) -> usize {
+    match ptype {
+        PheromoneType::Food => 0,
+        PheromoneType::Home => 1,
+        PheromoneType::Danger => 2,
+        PheromoneType::Recruit => 3,
+    }
+}
+
 /// Decay all pheromones
 pub fn pheromone_decay_system(pheromones: &mut PheromoneGrid, config: &SimConfig) {
-    pheromones.decay_all(&config.pheromone);
+    pheromones.decay_all(config);
 }
 
 /// Ants deposit pheromones as they walk
 pub fn pheromone_deposit_system(
     world: &World, pheromones: &mut PheromoneGrid, colonies: &[ColonyState], config: &SimConfig,
 ) {
-    for (_entity, (pos, ant, member)) in world.query::<(&Position, &Ant, &ColonyMember)>().iter() {
+    for (entity, (pos, ant, member)) in world.query::<(&Position, &Ant, &ColonyMember)>().iter() {
         let colony_id = member.colony_id;
+        let pheromone = config.pheromone_for(colony_id);
 
         match ant.state {
             // Carrying ants lay FOOD pheromone (they found food, others should follow)
             AntState::Carrying => {
                 pheromones.deposit_adaptive(
                     pos.x, pos.y, colony_id,
-                    PheromoneType::Food, config.pheromone.deposit_food,
+                    PheromoneType::Food, pheromone.deposit_food,
                 );
             }
             // Wandering/Returning ants lay HOME pheromone near nest only
@@ -245,11 +335,11 @@ pub fn pheromone_deposit_system(
                 if let Some(colony) = colonies.get(colony_id as usize) {
                     let dist = ((pos.x - colony.home_x).abs()
                         + (pos.y - colony.home_y).abs()) as f32;
-                    let proximity = (1.0 - dist / config.pheromone.home_deposit_radius).max(0.0);
+                    let proximity = (1.0 - dist / pheromone.home_deposit_radius).max(0.0);
                     if proximity > 0.0 {
                         pheromones.deposit_adaptive(
                             pos.x, pos.y, colony_id,
-                            PheromoneType::Home, config.pheromone.deposit_home * proximity,
+                            PheromoneType::Home, pheromone.deposit_home * proximity,
                         );
                     }
                 }
@@ -259,11 +349,11 @@ pub fn pheromone_deposit_system(
                 if let Some(colony) = colonies.get(colony_id as usize) {
                     let dist = ((pos.x - colony.home_x).abs()
                         + (pos.y - colony.home_y).abs()) as f32;
-                    let proximity = (1.0 - dist / config.pheromone.dig_deposit_radius).max(0.0);
+                    let proximity = (1.0 - dist / pheromone.dig_deposit_radius).max(0.0);
                     if proximity > 0.0 {
                         pheromones.deposit_adaptive(
                             pos.x, pos.y, colony_id,
-                            PheromoneType::Home, config.pheromone.deposit_home * config.pheromone.dig_deposit_multiplier * proximity,
+                            PheromoneType::Home, pheromone.deposit_home * pheromone.dig_deposit_multiplier * proximity,
                         );
                     }
                 }
@@ -271,19 +361,43 @@ pub fn pheromone_deposit_system(
             // Other states don't deposit (combat system handles danger pheromone)
             _ => {}
         }
+
+        // Foragers returning from or leading to a rich source call for help,
+        // loudest at the source so the gradient leads recruits to the food
+        let source = world
+            .get::<&Recruiter>(entity)
+            .map(|r| (r.food_x, r.food_y))
+            .or_else(|_| world.get::<&TandemLeader>(entity).map(|t| (t.target_x, t.target_y)));
+        if let Ok((food_x, food_y)) = source {
+            let dist = ((pos.x - food_x).abs() + (pos.y - food_y).abs()) as f32;
+            let proximity = (1.0 - dist / pheromone.recruit_deposit_radius).max(0.0);
+            if proximity > 0.0 {
+                pheromones.deposit_adaptive(
+                    pos.x, pos.y, colony_id,
+                    PheromoneType::Recruit, pheromone.deposit_recruit * proximity,
+                );
+            }
+        }
     }
 }
 
-/// Get movement direction based on pheromone following
+/// Get movement direction based on pheromone following.
+/// Ants with a heading only sense the three tiles ahead of them.
 pub fn follow_pheromone(
     pheromones: &PheromoneGrid,
     x: i32,
     y: i32,
     colony: u8,
     ptype: PheromoneType,
+    heading: Option<Heading>,
     terrain: &Terrain,
 ) -> Option<(i32, i32)> {
-    if let Some((dx, dy)) = pheromones.get_gradient_weighted(x, y, colony, ptype) {
+    let gradient = match heading {
+        Some(heading) => pheromones.get_gradient_weighted_in(x, y, colony, ptype, &heading.cone()),
+        None => pheromones.get_gradient_weighted(x, y, colony, ptype),
+    };
+
+    if let Some((dx, dy)) = gradient {
         if terrain.is_passable(x + dx, y + dy) {
             return Some((dx, dy));
         }
@@ -1,8 +1,10 @@
 use hecs::World;
 
-use crate::colony::ColonyState;
+use crate::colony::{ColonyState, ColonyStatus};
 use crate::components::{Ant, AntRole, AntState, ColonyMember, Position};
 use crate::config::SimConfig;
+use crate::flow_field::FlowField;
+use crate::spatial::NO_COLONY;
 use crate::terrain::{Terrain, TerrainType};
 
 /// Spawn multiple colonies with queens and initial workers
@@ -20,8 +22,9 @@ pub fn spawn_colonies(
         if let Some((x, y)) = find_colony_spawn_position(terrain, &spawn_positions, config.spawn.min_colony_distance) {
             spawn_positions.push((x, y));
 
-            // Create colony state
-            let colony = ColonyState::new(colony_id as u8, x, y, config.colony.initial_food);
+            // Create colony state; species are handed out in registry order
+            let species = config.species.for_spawn(colony_id);
+            let colony = ColonyState::new(colony_id as u8, species, x, y, config.colony.initial_food);
 
             // Spawn queen at surface
             spawn_ant(world, x, y, colony_id as u8, AntRole::Queen);
@@ -58,6 +61,72 @@ pub fn spawn_colonies(
     colonies
 }
 
+/// Land a fertilized queen on open ground away from living colonies, dig a
+/// founding chamber under the landing spot and start a new colony there.
+/// The new colony keeps its mother colony's species.
+pub fn found_colony(
+    world: &mut World,
+    terrain: &mut Terrain,
+    colonies: &[ColonyState],
+    species: usize,
+    flow_field: &mut FlowField,
+    config: &SimConfig,
+) -> Option<ColonyState> {
+    // Reuse an extinct colony's slot before growing the list; ids must stay
+    // below NO_COLONY
+    let colony_id = colonies
+        .iter()
+        .position(|c| c.status == ColonyStatus::Extinct)
+        .unwrap_or(colonies.len());
+    if colony_id >= NO_COLONY as usize {
+        return None;
+    }
+    let colony_id = colony_id as u8;
+
+    let min_distance = config.spawn.min_colony_distance;
+    let existing: Vec<(i32, i32)> = colonies
+        .iter()
+        .filter(|c| c.status != ColonyStatus::Extinct)
+        .map(|c| (c.home_x, c.home_y))
+        .collect();
+
+    // The search falls back to any surface tile; a founder won't settle that close
+    let (x, y) = find_colony_spawn_position(terrain, &existing, min_distance)?;
+    if existing.iter().any(|(ex, ey)| (x - ex).abs() + (y - ey).abs() < min_distance) {
+        return None;
+    }
+
+    // Short shaft straight down, widened into a chamber at the bottom deep
+    // enough to serve as the first nursery
+    let depth = config.colony.founding_depth.max(config.lifecycle.nursery_min_depth);
+    let mut chamber = (x, y);
+    for dy in 1..=depth {
+        if terrain.is_diggable(x, y + dy) {
+            terrain.set(x, y + dy, TerrainType::Tunnel);
+            flow_field.mark_changed(x, y + dy);
+        } else if !terrain.is_passable(x, y + dy) {
+            break;
+        }
+        chamber = (x, y + dy);
+    }
+    for dx in [-1, 1] {
+        let (cx, cy) = (chamber.0 + dx, chamber.1);
+        if chamber != (x, y) && terrain.is_diggable(cx, cy) {
+            terrain.set(cx, cy, TerrainType::Tunnel);
+            flow_field.mark_changed(cx, cy);
+        }
+    }
+
+    spawn_ant(world, chamber.0, chamber.1, colony_id, AntRole::Queen);
+
+    // The queen rears her first brood in the chamber she sealed herself into
+    let mut colony = ColonyState::new(colony_id, species, x, y, config.colony.founding_food);
+    if chamber.1 - y >= config.lifecycle.nursery_min_depth {
+        colony.nursery = Some(chamber);
+    }
+    Some(colony)
+}
+
 /// Find a valid spawn position on the surface
 fn find_colony_spawn_position(terrain: &Terrain, existing: &[(i32, i32)], min_colony_distance: i32) -> Option<(i32, i32)> {
     // Try random positions until we find a valid one
@@ -98,7 +167,7 @@ fn find_colony_spawn_position(terrain: &Terrain, existing: &[(i32, i32)], min_co
 }
 
 /// Spawn a single ant entity
-fn spawn_ant(world: &mut World, x: i32, y: i32, colony_id: u8, role: AntRole) {
+pub fn spawn_ant(world: &mut World, x: i32, y: i32, colony_id: u8, role: AntRole) {
     let state = match role {
         AntRole::Queen => AntState::Idle,
         AntRole::Worker => AntState::Wandering,
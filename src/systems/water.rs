@@ -2,6 +2,7 @@
 
 use hecs::World;
 
+use crate::colony::DeathCause;
 use crate::components::{Ant, AntState, Dead, Drowning, Position};
 use crate::config::SimConfig;
 use crate::terrain::Terrain;
@@ -309,7 +310,7 @@ pub fn drowning_system(world: &mut World, water: &WaterGrid, config: &SimConfig)
     }
 
     for entity in to_kill {
-        let _ = world.insert_one(entity, Dead);
+        let _ = world.insert(entity, (Dead, DeathCause::Drowned));
     }
 }
 